@@ -0,0 +1,32 @@
+//! Compares the throughput of the checked and unchecked `Guess` constructors.
+//! Run with `cargo bench`.
+
+use guessing_utils::Guess;
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: i32 = 10_000_000;
+
+fn bench(name: &str, f: impl Fn(i32) -> Guess) -> Duration {
+    let start = Instant::now();
+    let mut sum = 0i64;
+
+    for i in 0..ITERATIONS {
+        sum += i64::from(f(black_box(i % 101)).get());
+    }
+
+    let elapsed = start.elapsed();
+    black_box(sum);
+    println!("{:<14} {:>10.2?} ({:.2} ns/iter)", name, elapsed, elapsed.as_nanos() as f64 / ITERATIONS as f64);
+
+    elapsed
+}
+
+fn main() {
+    let checked = bench("new", |val| Guess::new(val).unwrap());
+    // SAFETY: `i % 101` is always in (0..101) range for non-negative `i`.
+    let unchecked = bench("new_unchecked", |val| unsafe { Guess::new_unchecked(val) });
+
+    println!("speedup: {:.2}x", checked.as_secs_f64() / unchecked.as_secs_f64());
+}
@@ -0,0 +1,118 @@
+//! Comparisons between `Guess` and the primitive integer types.
+
+use crate::Guess;
+
+/// Implements `PartialEq` in both directions between `Guess` and the provided integer types.
+/// All the values are widened to `i64`, so comparisons are exact for every value of these types.
+macro_rules! impl_partial_eq {
+    ($($t:ty),*) => {
+        $(
+            impl PartialEq<$t> for Guess {
+                fn eq(&self, other: &$t) -> bool {
+                    i64::from(self.val) == i64::from(*other)
+                }
+            }
+
+            impl PartialEq<Guess> for $t {
+                fn eq(&self, other: &Guess) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+
+// Allows expressions like `guess == 42` and `42 == guess`.
+impl_partial_eq!(i32, i64, u32, u8);
+
+/// Allows expressions like `guess < 50` without extracting the value first.
+/// The ordering is consistent with comparing two guesses.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// let guess = Guess::new(30).unwrap();
+/// 
+/// assert!(guess < 50);
+/// assert!(75 > guess);
+/// ```
+impl PartialOrd<i32> for Guess {
+    fn partial_cmp(&self, other: &i32) -> Option<core::cmp::Ordering> {
+        Some(self.val.cmp(other))
+    }
+}
+
+impl PartialOrd<Guess> for i32 {
+    fn partial_cmp(&self, other: &Guess) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(&other.val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eq_primitive_test() {
+        let guess = Guess::new(42).unwrap();
+
+        assert_eq!(guess, 42_i32);
+        assert_eq!(42_i32, guess);
+        assert_eq!(guess, 42_i64);
+        assert_eq!(42_i64, guess);
+        assert_eq!(guess, 42_u32);
+        assert_eq!(42_u32, guess);
+        assert_eq!(guess, 42_u8);
+        assert_eq!(42_u8, guess);
+
+        assert!(guess != 99);
+        assert!(99 != guess);
+        assert!(guess != 42_i64 + (1 << 32));
+    }
+
+    #[test]
+    fn eq_consistency_test() {
+        let guess = Guess::new(42).unwrap();
+
+        for val in 0..=100 {
+            assert_eq!(guess == val, guess == Guess::new(val).unwrap());
+        }
+    }
+
+    #[test]
+    fn ord_primitive_test() {
+        let guess = Guess::new(50).unwrap();
+
+        assert!(guess < 51);
+        assert!(guess <= 50);
+        assert!(guess > 49);
+        assert!(guess >= 50);
+        assert!(guess != 49);
+
+        assert!(49 < guess);
+        assert!(50 <= guess);
+        assert!(51 > guess);
+        assert!(50 >= guess);
+        assert!(41 != guess);
+    }
+
+    #[test]
+    fn ord_consistency_test() {
+        let guess = Guess::new(50).unwrap();
+
+        for val in 0..=100 {
+            assert_eq!(Some(guess.cmp(&Guess::new(val).unwrap())), guess.partial_cmp(&val));
+            assert_eq!(Some(val.cmp(&50)), val.partial_cmp(&guess));
+        }
+    }
+
+    #[test]
+    fn sort_key_test() {
+        let mut pairs = [(Guess::new(70).unwrap(), 1), (Guess::new(10).unwrap(), 2), (Guess::new(40).unwrap(), 3)];
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0.get()).unwrap());
+
+        let sorted: Vec<i32> = pairs.iter().map(|(guess, _)| guess.get()).collect();
+        assert_eq!(sorted, vec![10, 40, 70]);
+        assert!(pairs.iter().filter(|(guess, _)| *guess < 50).count() == 2);
+    }
+}
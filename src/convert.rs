@@ -0,0 +1,709 @@
+//! Conversions between `Guess` and the primitive types.
+
+use crate::err::{GuessError, GuessRangeError};
+use crate::Guess;
+
+/// Picks the value reported for an out-of-range float, rounded away from the valid range
+/// so it is never shown as a valid guess. NaN is reported as `i32::MIN`.
+fn out_of_range_value(x: f64) -> i32 {
+    if x.is_nan() {
+        i32::MIN
+    } else if x < 0.0 {
+        crate::float::floor(x) as i32
+    } else {
+        crate::float::ceil(x) as i32
+    }
+}
+
+/// Allows creating a guess with the standard `TryFrom` and `TryInto` traits.
+/// Behaves exactly like `Guess::new(val: i32)`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::err::GuessRangeError;
+/// 
+/// fn make_guess(val: i32) -> Result<Guess, GuessRangeError> {
+///     let guess: Guess = val.try_into()?;
+///     Ok(guess)
+/// }
+/// 
+/// assert!(make_guess(42).is_ok());
+/// assert!(make_guess(101).is_err());
+/// ```
+impl TryFrom<i32> for Guess {
+    type Error = GuessRangeError;
+
+    fn try_from(val: i32) -> Result<Self, Self::Error> {
+        Guess::new(val)
+    }
+}
+
+impl Guess {
+    /// Creates a new object from a single ASCII digit byte, mapping `b'0'`-`b'9'` to `0`-`9`.
+    /// Any other byte will produce an error.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_ascii_digit(b'7'), Ok(Guess::new(7).unwrap()));
+    /// assert!(Guess::from_ascii_digit(b'x').is_err());
+    /// ```
+    pub fn from_ascii_digit(byte: u8) -> Result<Guess, GuessError> {
+        Guess::try_from(byte as char)
+    }
+
+    /// Creates a new object from two ASCII digit bytes, the tens and the units.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_ascii_two_digits(b'4', b'2'), Ok(Guess::new(42).unwrap()));
+    /// ```
+    pub fn from_ascii_two_digits(tens: u8, units: u8) -> Result<Guess, GuessError> {
+        Guess::from_ascii_digits(&[tens, units])
+    }
+
+    /// Creates a new object from three ASCII digit bytes, the hundreds, the tens and the units.
+    /// Leading zeros are accepted, so apart from them only `b"100"` is in range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_ascii_three_digits(b'1', b'0', b'0'), Ok(Guess::MAX));
+    /// assert!(Guess::from_ascii_three_digits(b'1', b'0', b'1').is_err());
+    /// ```
+    pub fn from_ascii_three_digits(h: u8, t: u8, u: u8) -> Result<Guess, GuessError> {
+        Guess::from_ascii_digits(&[h, t, u])
+    }
+
+    /// Composes a number from ASCII digit bytes, most significant first.
+    fn from_ascii_digits(bytes: &[u8]) -> Result<Guess, GuessError> {
+        let mut val = 0;
+        for &byte in bytes {
+            val = val * 10 + Guess::from_ascii_digit(byte)?.val;
+        }
+
+        Ok(Guess::new(val)?)
+    }
+
+    /// Creates a new object from two digit characters, composing a two-digit number.
+    /// An error will be produced if either character is not a decimal digit.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_digit_chars('4', '2'), Ok(Guess::new(42).unwrap()));
+    /// assert_eq!(Guess::from_digit_chars('0', '5'), Ok(Guess::new(5).unwrap()));
+    /// ```
+    pub fn from_digit_chars(hi: char, lo: char) -> Result<Guess, GuessError> {
+        let hi = Guess::try_from(hi)?;
+        let lo = Guess::try_from(lo)?;
+
+        Ok(Guess::new(hi.val * 10 + lo.val)?)
+    }
+
+    /// Maps the guess onto the unit interval, so `0` becomes `0.0` and `100` becomes `1.0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(50).unwrap().normalize(), 0.5);
+    /// assert_eq!(Guess::MAX.normalize(), 1.0);
+    /// ```
+    pub fn normalize(&self) -> f64 {
+        self.val as f64 / 100.0
+    }
+
+    /// Creates a new object from a value in the unit interval, the inverse of `normalize()`.
+    /// The value is scaled to (0..101) range and rounded to the nearest integer.
+    /// An error will be returned for values outside `[0.0, 1.0]`, NaN and infinities.
+    /// The scaled value in the error is rounded away from the range, so `-0.001` reports `-1`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use guessing_utils::err::GuessRangeError;
+    /// 
+    /// assert_eq!(Guess::from_normalized(0.42), Ok(Guess::new(42).unwrap()));
+    /// assert_eq!(Guess::from_normalized(-0.001), Err(GuessRangeError(-1)));
+    /// assert!(Guess::from_normalized(f64::NAN).is_err());
+    /// ```
+    pub fn from_normalized(f: f64) -> Result<Guess, GuessRangeError> {
+        let scaled = f * 100.0;
+
+        if !(0.0..=1.0).contains(&f) {
+            return Err(GuessRangeError(out_of_range_value(scaled)));
+        }
+
+        Guess::new(crate::float::round(scaled) as i32)
+    }
+
+    /// Creates a new object from a percentage in `[0.0, 100.0]`,
+    /// rounding it to the nearest integer (halfway cases are rounded away from zero).
+    /// An error will be returned for values outside the range, NaN and infinities.
+    /// The value in the error is rounded away from the range, so `-0.1` reports `-1`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use guessing_utils::err::GuessRangeError;
+    /// 
+    /// assert_eq!(Guess::from_percentage(74.6), Ok(Guess::new(75).unwrap()));
+    /// assert_eq!(Guess::from_percentage(100.2), Err(GuessRangeError(101)));
+    /// ```
+    pub fn from_percentage(pct: f64) -> Result<Guess, GuessRangeError> {
+        if !(0.0..=100.0).contains(&pct) {
+            return Err(GuessRangeError(out_of_range_value(pct)));
+        }
+
+        Guess::new(crate::float::round(pct) as i32)
+    }
+
+    /// Gets the value stored in the object as a percentage in `[0.0, 100.0]`.
+    /// Use `normalize()` for a fraction in `[0.0, 1.0]` instead.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(75).unwrap().to_percentage_f64(), 75.0);
+    /// ```
+    pub fn to_percentage_f64(&self) -> f64 {
+        self.val as f64
+    }
+
+    /// Creates a new object from a fraction in `[0.0, 1.0]`.
+    /// This is the same as `Guess::from_normalized(f: f64)`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_fraction(0.75), Ok(Guess::new(75).unwrap()));
+    /// ```
+    pub fn from_fraction(f: f64) -> Result<Guess, GuessRangeError> {
+        Guess::from_normalized(f)
+    }
+
+    /// Encodes the value stored in the object as a single byte.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().to_bytes(), [42]);
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 1] {
+        [self.val as u8]
+    }
+
+    /// Creates a new object from a single byte, the inverse of `to_bytes()`.
+    /// An error will be returned if the byte is greater than 100.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_bytes([42]), Ok(Guess::new(42).unwrap()));
+    /// assert!(Guess::from_bytes([200]).is_err());
+    /// ```
+    pub fn from_bytes(bytes: [u8; 1]) -> Result<Guess, GuessRangeError> {
+        Guess::new(bytes[0] as i32)
+    }
+
+    /// Encodes the value stored in the object as a little-endian `i32`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().to_le_bytes(), [42, 0, 0, 0]);
+    /// ```
+    pub fn to_le_bytes(&self) -> [u8; 4] {
+        self.val.to_le_bytes()
+    }
+
+    /// Creates a new object from a little-endian `i32`, the inverse of `to_le_bytes()`.
+    /// An error will be returned if the decoded value is outside (0..101) range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_le_bytes([42, 0, 0, 0]), Ok(Guess::new(42).unwrap()));
+    /// assert!(Guess::from_le_bytes([42, 0, 0, 1]).is_err());
+    /// ```
+    pub fn from_le_bytes(bytes: [u8; 4]) -> Result<Guess, GuessRangeError> {
+        Guess::new(i32::from_le_bytes(bytes))
+    }
+}
+
+/// Allows creating a guess from a single digit character, mapping `'0'`-`'9'` to `0`-`9`.
+/// Any other character will produce `GuessError::InvalidDigit`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::err::GuessError;
+/// 
+/// assert_eq!(Guess::try_from('3'), Ok(Guess::new(3).unwrap()));
+/// assert_eq!(Guess::try_from('a'), Err(GuessError::InvalidDigit('a')));
+/// ```
+impl TryFrom<char> for Guess {
+    type Error = GuessError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_digit(10) {
+            Some(digit) => Ok(Guess { val: digit as i32 }),
+            None => Err(GuessError::InvalidDigit(c)),
+        }
+    }
+}
+
+/// Implements `TryFrom` for unsigned integer types.
+/// Values which do not even fit into an `i32` are reported as `i32::MAX` in the returned error.
+macro_rules! impl_try_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<$t> for Guess {
+                type Error = GuessRangeError;
+
+                fn try_from(val: $t) -> Result<Self, Self::Error> {
+                    let val = i32::try_from(val).map_err(|_| GuessRangeError(i32::MAX))?;
+                    Guess::new(val)
+                }
+            }
+        )*
+    };
+}
+
+// Unsigned integers can only fail for values greater than 100.
+impl_try_from_unsigned!(u8, u16, u32, u64, usize);
+
+/// Extracts the value stored in the guess as an `i64`. This conversion never fails.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert_eq!(i64::from(Guess::new(42).unwrap()), 42);
+/// ```
+impl From<Guess> for i64 {
+    fn from(guess: Guess) -> Self {
+        guess.val.into()
+    }
+}
+
+/// Allows creating a guess from a signed byte, failing for negative values and values greater than 100.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert!(Guess::try_from(42i8).is_ok());
+/// assert!(Guess::try_from(-1i8).is_err());
+/// ```
+impl TryFrom<i8> for Guess {
+    type Error = GuessRangeError;
+
+    fn try_from(val: i8) -> Result<Self, Self::Error> {
+        Guess::new(val.into())
+    }
+}
+
+/// Extracts the value stored in the guess. This conversion never fails.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// let guess = Guess::new(42).unwrap();
+/// let val: i32 = guess.into();
+/// 
+/// assert_eq!(val, 42);
+/// ```
+impl From<Guess> for i32 {
+    fn from(guess: Guess) -> Self {
+        guess.val
+    }
+}
+
+/// Extracts the value stored in the guess as a byte.
+/// This is a lossless conversion, as every valid value fits into a `u8`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// let byte: u8 = Guess::new(100).unwrap().into();
+/// 
+/// assert_eq!(byte, 100);
+/// ```
+impl From<Guess> for u8 {
+    fn from(guess: Guess) -> Self {
+        guess.val as u8
+    }
+}
+
+/// Maps `false` to the smallest guess and `true` to the largest one,
+/// handy for boundary values in tests and for games driven by yes or no questions.
+/// Converting back with `bool::from` tells if the guess is above the midpoint,
+/// so the round-trip starting from a guess is not the identity.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert_eq!(Guess::from(false), Guess::MIN);
+/// assert_eq!(Guess::from(true), Guess::MAX);
+/// assert!(!bool::from(Guess::from(false)));
+/// assert_eq!(Guess::from(bool::from(Guess::new(30).unwrap())), Guess::MIN);
+/// ```
+impl From<bool> for Guess {
+    fn from(b: bool) -> Self {
+        if b {
+            Guess::MAX
+        } else {
+            Guess::MIN
+        }
+    }
+}
+
+/// Checks if the guess is above the midpoint, answering "is the number above 50?".
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert!(bool::from(Guess::new(51).unwrap()));
+/// assert!(!bool::from(Guess::MIDPOINT));
+/// ```
+impl From<Guess> for bool {
+    fn from(guess: Guess) -> Self {
+        guess > Guess::MIDPOINT
+    }
+}
+
+/// Implements `From<Guess>` for types which can hold every valid value without any loss.
+macro_rules! impl_from_guess {
+    ($($t:ty),*) => {
+        $(
+            impl From<Guess> for $t {
+                fn from(guess: Guess) -> Self {
+                    guess.val as $t
+                }
+            }
+        )*
+    };
+}
+
+// The values are never negative, so the unsigned conversions are infallible as well.
+impl_from_guess!(u32, u64, usize, f32, f64);
+
+impl AsRef<i32> for Guess {
+    fn as_ref(&self) -> &i32 {
+        &self.val
+    }
+}
+
+/// Allows looking up guesses stored in hashed and ordered collections by their raw value.
+/// Hashing, equality and ordering of a guess all agree with the ones of the value it holds.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use std::collections::HashMap;
+/// 
+/// let mut hints: HashMap<Guess, &str> = HashMap::new();
+/// hints.insert(Guess::new(42).unwrap(), "Too big!");
+/// 
+/// assert_eq!(hints.get(&42), Some(&"Too big!"));
+/// ```
+impl core::borrow::Borrow<i32> for Guess {
+    fn borrow(&self) -> &i32 {
+        &self.val
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum MyError {
+        InvalidGuess,
+    }
+
+    fn to_guess(val: i32) -> Result<Guess, MyError> {
+        let guess: Guess = val.try_into().map_err(|_| MyError::InvalidGuess)?;
+        Ok(guess)
+    }
+
+    #[test]
+    fn try_from_i32_test() {
+        assert_eq!(Guess::try_from(0), Guess::new(0));
+        assert_eq!(Guess::try_from(100), Guess::new(100));
+        assert_eq!(Guess::try_from(-1), Err(GuessRangeError(-1)));
+        assert_eq!(Guess::try_from(101), Err(GuessRangeError(101)));
+
+        assert_eq!(to_guess(50), Ok(Guess::new(50).unwrap()));
+        assert_eq!(to_guess(500), Err(MyError::InvalidGuess));
+    }
+
+    #[test]
+    fn try_from_iterator_test() {
+        let guesses: Vec<Guess> = [-5, 10, 200, 20]
+            .into_iter()
+            .flat_map(Guess::try_from)
+            .collect();
+
+        assert_eq!(guesses, vec![Guess::new(10).unwrap(), Guess::new(20).unwrap()]);
+    }
+
+    #[test]
+    fn try_from_unsigned_test() {
+        let guess: Result<Guess, _> = 100u32.try_into();
+        assert_eq!(guess, Guess::new(100));
+
+        let guess: Result<Guess, _> = 101u32.try_into();
+        assert_eq!(guess, Err(GuessRangeError(101)));
+
+        let guess: Result<Guess, _> = u32::MAX.try_into();
+        assert_eq!(guess, Err(GuessRangeError(i32::MAX)));
+
+        let guess: Result<Guess, _> = 0usize.try_into();
+        assert_eq!(guess, Guess::new(0));
+
+        assert_eq!(Guess::try_from(1u64 << 32), Err(GuessRangeError(i32::MAX)));
+        assert_eq!(Guess::try_from(255u8), Err(GuessRangeError(255)));
+        assert_eq!(Guess::try_from(100u16), Guess::new(100));
+    }
+
+    #[test]
+    fn try_from_bytes_test() {
+        let guess: Result<Guess, _> = 100u8.try_into();
+        assert_eq!(guess, Guess::new(100));
+
+        let guess: Result<Guess, _> = 101u8.try_into();
+        assert!(guess.is_err());
+
+        let guess: Result<Guess, _> = 255u8.try_into();
+        assert!(guess.is_err());
+
+        let guess: Result<Guess, _> = (-1i8).try_into();
+        assert_eq!(guess, Err(GuessRangeError(-1)));
+
+        let guess: Result<Guess, _> = 0i8.try_into();
+        assert_eq!(guess, Guess::new(0));
+
+        assert_eq!(Guess::try_from(i8::MAX), Err(GuessRangeError(127)));
+        assert_eq!(Guess::try_from(i8::MIN), Err(GuessRangeError(-128)));
+    }
+
+    #[test]
+    fn into_i64_test() {
+        let guess = Guess::new(64).unwrap();
+        let val: i64 = guess.into();
+
+        assert_eq!(val, 64);
+        assert_eq!(i64::from(guess), i64::from(guess.get()));
+    }
+
+    #[test]
+    fn accessors_test() {
+        let guess = Guess::new(33).unwrap();
+
+        assert_eq!(guess.get(), 33);
+        assert_eq!(guess.into_inner(), 33);
+        assert_eq!(guess.get() * 3, 99);
+    }
+
+    #[test]
+    fn into_unsigned_test() {
+        let mut histogram = [0u32; 101];
+        let guess = Guess::new(42).unwrap();
+
+        let idx: usize = guess.into();
+        histogram[idx] += 1;
+
+        assert_eq!(histogram[42], 1);
+        assert_eq!(u32::from(guess), 42);
+        assert_eq!(u64::from(guess), 42);
+
+        for idx in 0usize..=100 {
+            assert_eq!(usize::from(Guess::try_from(idx).unwrap()), idx);
+        }
+    }
+
+    #[test]
+    fn into_float_test() {
+        let guesses = [Guess::new(25).unwrap(), Guess::new(50).unwrap()];
+        let mean = guesses.iter().copied().map(f64::from).sum::<f64>() / guesses.len() as f64;
+
+        assert_eq!(mean, 37.5);
+        assert_eq!(f32::from(Guess::MAX), 100.0);
+    }
+
+    #[test]
+    fn into_iterator_test() {
+        let guesses = vec![Guess::new(1).unwrap(), Guess::new(2).unwrap()];
+        let values = guesses.into_iter().map(i32::from).collect::<Vec<_>>();
+
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn into_u8_test() {
+        for val in 0u8..=100 {
+            let guess = Guess::try_from(val).unwrap();
+            assert_eq!(u8::from(guess), val);
+        }
+    }
+
+    #[test]
+    fn into_i32_test() {
+        let guess = Guess::new(77).unwrap();
+
+        assert_eq!(i32::from(guess), 77);
+        assert_eq!(i32::from(Guess::try_from(i32::from(guess)).unwrap()), 77);
+    }
+
+    #[test]
+    fn as_ref_test() {
+        fn show<T: AsRef<i32>>(val: &T) -> String {
+            val.as_ref().to_string()
+        }
+
+        assert_eq!(show(&Guess::new(8).unwrap()), "8");
+    }
+
+    #[test]
+    fn borrow_lookup_test() {
+        use std::collections::{BTreeSet, HashMap};
+
+        let mut hints: HashMap<Guess, &str> = HashMap::new();
+        hints.insert(Guess::new(30).unwrap(), "Too small!");
+        hints.insert(Guess::new(80).unwrap(), "Too big!");
+
+        assert_eq!(hints.get(&30i32), Some(&"Too small!"));
+        assert_eq!(hints.get(&80i32), Some(&"Too big!"));
+        assert_eq!(hints.get(&50i32), None);
+        assert!(hints.contains_key(&30i32));
+
+        let set: BTreeSet<Guess> = [Guess::new(1).unwrap(), Guess::new(2).unwrap()].into();
+        assert!(set.contains(&2i32));
+    }
+
+    #[test]
+    fn try_from_char_test() {
+        for (i, c) in ('0'..='9').enumerate() {
+            assert_eq!(Guess::try_from(c), Ok(Guess::new(i as i32).unwrap()));
+            assert_eq!(Guess::from_ascii_digit(c as u8), Ok(Guess::new(i as i32).unwrap()));
+        }
+
+        for c in ['a', ' ', '\n', '-', '\u{0663}'] {
+            assert_eq!(Guess::try_from(c), Err(GuessError::InvalidDigit(c)));
+        }
+
+        assert_eq!(Guess::from_ascii_digit(b'/'), Err(GuessError::InvalidDigit('/')));
+        assert_eq!(Guess::from_ascii_digit(b':'), Err(GuessError::InvalidDigit(':')));
+    }
+
+    #[test]
+    fn from_digit_chars_test() {
+        assert_eq!(Guess::from_digit_chars('9', '9'), Ok(Guess::new(99).unwrap()));
+        assert_eq!(Guess::from_digit_chars('0', '0'), Ok(Guess::new(0).unwrap()));
+        assert_eq!(Guess::from_digit_chars('1', 'x'), Err(GuessError::InvalidDigit('x')));
+        assert_eq!(Guess::from_digit_chars(' ', '1'), Err(GuessError::InvalidDigit(' ')));
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(Guess::MIN.normalize(), 0.0);
+        assert_eq!(Guess::new(50).unwrap().normalize(), 0.5);
+        assert_eq!(Guess::MAX.normalize(), 1.0);
+
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+            assert_eq!(Guess::from_normalized(guess.normalize()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn from_normalized_test() {
+        assert_eq!(Guess::from_normalized(0.994), Ok(Guess::new(99).unwrap()));
+        assert_eq!(Guess::from_normalized(1.01), Err(GuessRangeError(101)));
+        assert_eq!(Guess::from_normalized(-0.5), Err(GuessRangeError(-50)));
+        assert_eq!(Guess::from_normalized(-0.001), Err(GuessRangeError(-1)));
+        assert_eq!(Guess::from_normalized(1.001), Err(GuessRangeError(101)));
+        assert_eq!(Guess::from_normalized(f64::NAN), Err(GuessRangeError(i32::MIN)));
+        assert!(Guess::from_normalized(f64::INFINITY).is_err());
+        assert!(Guess::from_normalized(f64::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn from_percentage_test() {
+        assert_eq!(Guess::from_percentage(0.0), Ok(Guess::MIN));
+        assert_eq!(Guess::from_percentage(100.0), Ok(Guess::MAX));
+        assert_eq!(Guess::from_percentage(42.5), Ok(Guess::new(43).unwrap()));
+        assert_eq!(Guess::from_percentage(42.49), Ok(Guess::new(42).unwrap()));
+        assert_eq!(Guess::from_percentage(-0.1), Err(GuessRangeError(-1)));
+        assert_eq!(Guess::from_percentage(100.1), Err(GuessRangeError(101)));
+        assert_eq!(Guess::from_percentage(150.0), Err(GuessRangeError(150)));
+        assert_eq!(Guess::from_percentage(f64::INFINITY), Err(GuessRangeError(i32::MAX)));
+        assert_eq!(Guess::from_percentage(f64::NEG_INFINITY), Err(GuessRangeError(i32::MIN)));
+        assert_eq!(Guess::from_percentage(f64::NAN), Err(GuessRangeError(i32::MIN)));
+        assert_eq!(Guess::from_percentage(-0.1).unwrap_err().to_string(), "-1 is outside 0-100");
+    }
+
+    #[test]
+    fn percentage_round_trip_test() {
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+
+            assert_eq!(Guess::from_percentage(guess.to_percentage_f64()), Ok(guess));
+            assert_eq!(Guess::from_fraction(guess.normalize()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip_test() {
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+
+            assert_eq!(guess.to_bytes(), [val as u8]);
+            assert_eq!(Guess::from_bytes(guess.to_bytes()), Ok(guess));
+            assert_eq!(Guess::from_le_bytes(guess.to_le_bytes()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn from_bytes_invalid_test() {
+        for byte in 101..=255u8 {
+            assert_eq!(Guess::from_bytes([byte]), Err(GuessRangeError(byte as i32)));
+        }
+
+        assert_eq!(Guess::from_le_bytes((-1i32).to_le_bytes()), Err(GuessRangeError(-1)));
+        assert_eq!(Guess::from_le_bytes(101i32.to_le_bytes()), Err(GuessRangeError(101)));
+    }
+
+    #[test]
+    fn bool_conversion_test() {
+        assert_eq!(Guess::from(false), Guess::MIN);
+        assert_eq!(Guess::from(true), Guess::MAX);
+
+        for val in 0..=100 {
+            assert_eq!(bool::from(Guess::new(val).unwrap()), val > 50);
+        }
+
+        assert!(!bool::from(Guess::from(false)));
+        assert!(bool::from(Guess::from(true)));
+        assert_eq!(Guess::from(bool::from(Guess::new(30).unwrap())), Guess::MIN);
+        assert_eq!(Guess::from(bool::from(Guess::new(70).unwrap())), Guess::MAX);
+    }
+
+    #[test]
+    fn from_ascii_multiple_digits_test() {
+        assert_eq!(Guess::from_ascii_two_digits(b'0', b'0'), Ok(Guess::MIN));
+        assert_eq!(Guess::from_ascii_two_digits(b'0', b'9'), Ok(Guess::new(9).unwrap()));
+        assert_eq!(Guess::from_ascii_two_digits(b'1', b'0'), Ok(Guess::new(10).unwrap()));
+        assert_eq!(Guess::from_ascii_two_digits(b'9', b'9'), Ok(Guess::new(99).unwrap()));
+        assert_eq!(Guess::from_ascii_two_digits(b'9', b'x'), Err(GuessError::InvalidDigit('x')));
+        assert_eq!(Guess::from_ascii_two_digits(b'-', b'1'), Err(GuessError::InvalidDigit('-')));
+
+        assert_eq!(Guess::from_ascii_three_digits(b'1', b'0', b'0'), Ok(Guess::MAX));
+        assert_eq!(Guess::from_ascii_three_digits(b'0', b'4', b'2'), Ok(Guess::new(42).unwrap()));
+        assert_eq!(Guess::from_ascii_three_digits(b'1', b'0', b'1'), Err(GuessRangeError(101).into()));
+        assert_eq!(Guess::from_ascii_three_digits(b'9', b'9', b'9'), Err(GuessRangeError(999).into()));
+        assert_eq!(Guess::from_ascii_three_digits(b'1', b'O', b'0'), Err(GuessError::InvalidDigit('O')));
+
+        for val in 0..=100 {
+            let [h, t, u] = format!("{:03}", val).into_bytes()[..] else { unreachable!() };
+            assert_eq!(Guess::from_ascii_three_digits(h, t, u), Ok(Guess::new(val).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,158 @@
+//! Signed steps between guesses.
+
+use crate::err::GuessRangeError;
+use crate::Guess;
+
+use alloc::format;
+use core::cmp::Ordering;
+use core::ops::{Add, Neg};
+
+/// The signed difference between two guesses, always in `[-100, 100]` when created by `GuessDiff::new`.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessDiff};
+/// 
+/// let diff = GuessDiff::new(&Guess::new(70).unwrap(), &Guess::new(30).unwrap());
+/// 
+/// assert_eq!(diff.delta, -40);
+/// assert_eq!(diff.to_string(), "-40");
+/// assert_eq!(diff.apply(&Guess::new(50).unwrap()), Ok(Guess::new(10).unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GuessDiff {
+    /// The signed distance, positive when moving towards larger values.
+    pub delta: i32,
+}
+
+impl GuessDiff {
+    /// Creates the step leading from `from` to `to`.
+    pub fn new(from: &Guess, to: &Guess) -> GuessDiff {
+        GuessDiff { delta: to.val - from.val }
+    }
+
+    /// Gets the length of the step, ignoring its direction.
+    pub fn abs(&self) -> u32 {
+        self.delta.unsigned_abs()
+    }
+
+    /// Checks if the step does not move at all.
+    pub fn is_zero(&self) -> bool {
+        self.delta == 0
+    }
+
+    /// Checks if the step moves towards larger values.
+    pub fn is_positive(&self) -> bool {
+        self.delta > 0
+    }
+
+    /// Gets the direction of the step as an ordering of the destination relative to the start.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessDiff};
+    /// 
+    /// use std::cmp::Ordering;
+    /// 
+    /// assert_eq!(GuessDiff::new(&Guess::MIN, &Guess::MAX).direction(), Ordering::Greater);
+    /// ```
+    pub fn direction(&self) -> Ordering {
+        self.delta.cmp(&0)
+    }
+
+    /// Moves `base` by the step.
+    /// An error will be returned if the result is outside (0..101) range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessDiff};
+    /// 
+    /// let diff = GuessDiff { delta: 30 };
+    /// 
+    /// assert_eq!(diff.apply(&Guess::new(50).unwrap()), Ok(Guess::new(80).unwrap()));
+    /// assert!(diff.apply(&Guess::new(80).unwrap()).is_err());
+    /// ```
+    pub fn apply(&self, base: &Guess) -> Result<Guess, GuessRangeError> {
+        *base + self.delta
+    }
+}
+
+impl core::fmt::Display for GuessDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&format!("{:+}", self.delta))
+    }
+}
+
+impl Add<GuessDiff> for GuessDiff {
+    type Output = GuessDiff;
+
+    fn add(self, rhs: GuessDiff) -> Self::Output {
+        GuessDiff { delta: self.delta.saturating_add(rhs.delta) }
+    }
+}
+
+/// Allows moving a guess by a step, the same as `GuessDiff::apply(&self, base: &Guess)`.
+impl Add<GuessDiff> for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn add(self, rhs: GuessDiff) -> Self::Output {
+        rhs.apply(&self)
+    }
+}
+
+impl Neg for GuessDiff {
+    type Output = GuessDiff;
+
+    fn neg(self) -> Self::Output {
+        GuessDiff { delta: self.delta.saturating_neg() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(val: i32) -> Guess {
+        Guess::new(val).unwrap()
+    }
+
+    #[test]
+    fn new_test() {
+        let diff = GuessDiff::new(&guess(70), &guess(30));
+
+        assert_eq!(diff.delta, -40);
+        assert_eq!(diff.abs(), 40);
+        assert!(!diff.is_zero());
+        assert!(!diff.is_positive());
+        assert_eq!(diff.direction(), Ordering::Less);
+        assert_eq!(GuessDiff::new(&Guess::MIN, &Guess::MAX).delta, 100);
+        assert!(GuessDiff::new(&guess(5), &guess(5)).is_zero());
+        assert_eq!(GuessDiff::new(&guess(5), &guess(5)).direction(), Ordering::Equal);
+    }
+
+    #[test]
+    fn apply_test() {
+        let diff = GuessDiff::new(&guess(70), &guess(30));
+
+        assert_eq!(diff.apply(&guess(50)), Ok(guess(10)));
+        assert_eq!(guess(50) + diff, Ok(guess(10)));
+        assert_eq!(diff.apply(&guess(30)), Err(GuessRangeError(-10)));
+        assert_eq!((-diff).apply(&guess(70)), Err(GuessRangeError(110)));
+
+        for from in [0, 42, 100] {
+            for to in [0, 17, 100] {
+                assert_eq!(GuessDiff::new(&guess(from), &guess(to)).apply(&guess(from)), Ok(guess(to)));
+            }
+        }
+    }
+
+    #[test]
+    fn ops_test() {
+        let up = GuessDiff { delta: 5 };
+        let down = GuessDiff { delta: -3 };
+
+        assert_eq!(up.to_string(), "+5");
+        assert_eq!(down.to_string(), "-3");
+        assert_eq!((up + down).delta, 2);
+        assert_eq!(-up, GuessDiff { delta: -5 });
+        assert!(down < up);
+        assert_eq!([up, down, GuessDiff { delta: 0 }].iter().max(), Some(&up));
+    }
+}
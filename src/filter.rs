@@ -0,0 +1,141 @@
+//! Predicate-based filtering of guesses.
+
+use crate::{Guess, GuessList};
+
+use alloc::boxed::Box;
+use core::fmt;
+
+/// A reusable predicate selecting guesses, which can be composed with `and` and `or`.
+/// # Example
+/// ```
+/// use guessing_utils::{all_guesses, Guess, GuessFilter};
+/// 
+/// let guesses: Vec<Guess> = all_guesses().collect();
+/// let filter = GuessFilter::primes().and(GuessFilter::below(10));
+/// 
+/// let primes: Vec<i32> = filter.apply(&guesses).into_iter().map(|g| g.get()).collect();
+/// 
+/// assert_eq!(primes, [2, 3, 5, 7]);
+/// ```
+pub struct GuessFilter {
+    predicate: Box<dyn Fn(&Guess) -> bool>,
+}
+
+impl GuessFilter {
+    /// Creates a filter keeping the guesses which satisfy the predicate.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessFilter};
+    /// 
+    /// let filter = GuessFilter::new(|g| g.get() % 10 == 0);
+    /// 
+    /// assert!(filter.matches(&Guess::new(30).unwrap()));
+    /// ```
+    pub fn new(predicate: impl Fn(&Guess) -> bool + 'static) -> GuessFilter {
+        GuessFilter { predicate: Box::new(predicate) }
+    }
+
+    /// Creates a filter keeping the even guesses.
+    pub fn evens() -> GuessFilter {
+        GuessFilter::new(Guess::is_even)
+    }
+
+    /// Creates a filter keeping the odd guesses.
+    pub fn odds() -> GuessFilter {
+        GuessFilter::new(Guess::is_odd)
+    }
+
+    /// Creates a filter keeping the prime guesses.
+    pub fn primes() -> GuessFilter {
+        GuessFilter::new(Guess::is_prime)
+    }
+
+    /// Creates a filter keeping the guesses in the inclusive range from `min` to `max`.
+    pub fn in_range(min: i32, max: i32) -> GuessFilter {
+        GuessFilter::new(move |g| (min..=max).contains(&g.val))
+    }
+
+    /// Creates a filter keeping the guesses strictly greater than the threshold.
+    pub fn above(threshold: i32) -> GuessFilter {
+        GuessFilter::new(move |g| g.val > threshold)
+    }
+
+    /// Creates a filter keeping the guesses strictly less than the threshold.
+    pub fn below(threshold: i32) -> GuessFilter {
+        GuessFilter::new(move |g| g.val < threshold)
+    }
+
+    /// Combines two filters, keeping the guesses which satisfy both.
+    pub fn and(self, other: GuessFilter) -> GuessFilter {
+        GuessFilter::new(move |g| (self.predicate)(g) && (other.predicate)(g))
+    }
+
+    /// Combines two filters, keeping the guesses which satisfy either.
+    pub fn or(self, other: GuessFilter) -> GuessFilter {
+        GuessFilter::new(move |g| (self.predicate)(g) || (other.predicate)(g))
+    }
+
+    /// Checks if the guess satisfies the filter.
+    pub fn matches(&self, guess: &Guess) -> bool {
+        (self.predicate)(guess)
+    }
+
+    /// Collects the guesses satisfying the filter into a sorted list without duplicates.
+    pub fn apply(&self, guesses: &[Guess]) -> GuessList {
+        guesses.iter().copied().filter(|g| self.matches(g)).collect()
+    }
+}
+
+impl fmt::Debug for GuessFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GuessFilter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::all_guesses;
+
+    fn values(filter: &GuessFilter) -> Vec<i32> {
+        let guesses: Vec<Guess> = all_guesses().collect();
+
+        filter.apply(&guesses).into_iter().map(|g| g.get()).collect()
+    }
+
+    #[test]
+    fn factory_filters_test() {
+        assert_eq!(values(&GuessFilter::evens()), (0..=100).step_by(2).collect::<Vec<_>>());
+        assert_eq!(values(&GuessFilter::odds()), (1..100).step_by(2).collect::<Vec<_>>());
+        assert_eq!(values(&GuessFilter::primes()).len(), 25);
+        assert_eq!(values(&GuessFilter::in_range(10, 20)), (10..=20).collect::<Vec<_>>());
+        assert!(values(&GuessFilter::in_range(20, 10)).is_empty());
+        assert_eq!(values(&GuessFilter::above(97)), [98, 99, 100]);
+        assert_eq!(values(&GuessFilter::below(3)), [0, 1, 2]);
+        assert!(values(&GuessFilter::above(100)).is_empty());
+    }
+
+    #[test]
+    fn composed_filters_test() {
+        let evens_in_range = GuessFilter::evens().and(GuessFilter::in_range(10, 50));
+        assert_eq!(values(&evens_in_range), (10..=50).filter(|v| v % 2 == 0).collect::<Vec<_>>());
+
+        let edges = GuessFilter::below(5).or(GuessFilter::above(95));
+        assert_eq!(values(&edges), [0, 1, 2, 3, 4, 96, 97, 98, 99, 100]);
+
+        let everything = GuessFilter::evens().or(GuessFilter::odds());
+        assert_eq!(values(&everything).len(), 101);
+
+        let nothing = GuessFilter::evens().and(GuessFilter::odds());
+        assert!(values(&nothing).is_empty());
+    }
+
+    #[test]
+    fn apply_test() {
+        let guesses: Vec<Guess> = [40, 3, 40, 8, 3].iter().map(|&v| Guess::new(v).unwrap()).collect();
+        let evens: Vec<i32> = GuessFilter::evens().apply(&guesses).into_iter().map(|g| g.get()).collect();
+
+        assert_eq!(evens, [8, 40]);
+        assert!(GuessFilter::primes().apply(&[]).is_empty());
+    }
+}
@@ -0,0 +1,169 @@
+//! A ready-to-play game combining a session, a difficulty and a hint strategy.
+
+use crate::strategy::{HintStrategy, SimpleHintStrategy};
+use crate::{Guess, GuessResult, GuessSession};
+
+use std::fmt;
+
+/// How many attempts the player gets to find the secret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    /// `15` attempts.
+    Easy,
+    /// `10` attempts.
+    Medium,
+    /// `7` attempts, just enough for a perfect binary search.
+    Hard,
+}
+
+impl Difficulty {
+    /// Gets the number of attempts the player gets at this difficulty.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Difficulty;
+    /// 
+    /// assert_eq!(Difficulty::Hard.max_attempts(), 7);
+    /// ```
+    pub fn max_attempts(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 15,
+            Difficulty::Medium => 10,
+            Difficulty::Hard => 7,
+        }
+    }
+}
+
+/// A game against a random secret, giving a hint from its strategy after every guess.
+/// # Example
+/// ```
+/// use guessing_utils::{Difficulty, Guess, GuessGame, GuessResult};
+/// 
+/// let mut game = GuessGame::new(Difficulty::Medium);
+/// let secret = game.session().secret();
+/// 
+/// let (result, hint) = game.play(secret);
+/// 
+/// assert_eq!(result, GuessResult::Correct);
+/// assert_eq!(hint, "Correct!");
+/// ```
+pub struct GuessGame {
+    session: GuessSession,
+    strategy: Box<dyn HintStrategy>,
+    difficulty: Difficulty,
+}
+
+impl GuessGame {
+    /// Creates a game with a random secret, giving simple hints.
+    pub fn new(difficulty: Difficulty) -> GuessGame {
+        GuessGame::with_strategy(difficulty, Box::new(SimpleHintStrategy))
+    }
+
+    /// Creates a game with a random secret, giving hints from the provided strategy.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Difficulty, GuessGame};
+    /// use guessing_utils::strategy::VerboseHintStrategy;
+    /// 
+    /// let game = GuessGame::with_strategy(Difficulty::Easy, Box::new(VerboseHintStrategy));
+    /// 
+    /// assert_eq!(game.session().max_attempts(), 15);
+    /// ```
+    pub fn with_strategy(difficulty: Difficulty, strategy: Box<dyn HintStrategy>) -> GuessGame {
+        GuessGame {
+            session: GuessSession::new_random(difficulty.max_attempts()),
+            strategy,
+            difficulty,
+        }
+    }
+
+    /// Makes a guess, returning its result and the hint generated by the strategy.
+    pub fn play(&mut self, guess: Guess) -> (GuessResult, String) {
+        let result = self.session.attempt(guess);
+        let hint = self.strategy.generate_hint(&self.session);
+
+        (result, hint)
+    }
+
+    /// Gets the session holding the state of the game.
+    pub fn session(&self) -> &GuessSession {
+        &self.session
+    }
+
+    /// Gets the difficulty the game was created with.
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+}
+
+impl fmt::Debug for GuessGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GuessGame")
+            .field("session", &self.session)
+            .field("difficulty", &self.difficulty)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{BinarySearchStrategy, VerboseHintStrategy};
+
+    const DIFFICULTIES: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    #[test]
+    fn difficulty_attempts_test() {
+        assert_eq!(Difficulty::Easy.max_attempts(), 15);
+        assert_eq!(Difficulty::Medium.max_attempts(), 10);
+        assert_eq!(Difficulty::Hard.max_attempts(), 7);
+
+        for difficulty in DIFFICULTIES {
+            let game = GuessGame::new(difficulty);
+
+            assert_eq!(game.difficulty(), difficulty);
+            assert_eq!(game.session().max_attempts(), difficulty.max_attempts());
+            assert_eq!(game.session().attempts_used(), 0);
+        }
+    }
+
+    #[test]
+    fn play_to_win_test() {
+        let search = BinarySearchStrategy::new();
+
+        for difficulty in DIFFICULTIES {
+            let mut game = GuessGame::new(difficulty);
+            let mut last = None;
+
+            while !game.session().is_over() {
+                let guess = search.suggest_next(game.session());
+                let (result, hint) = game.play(guess);
+
+                assert!(!hint.is_empty());
+                last = Some((result, hint));
+            }
+
+            assert!(game.session().is_won());
+            assert_eq!(last, Some((GuessResult::Correct, "Correct!".to_string())));
+        }
+    }
+
+    #[test]
+    fn play_to_loss_test() {
+        for difficulty in DIFFICULTIES {
+            let mut game = GuessGame::with_strategy(difficulty, Box::new(VerboseHintStrategy));
+            let secret = game.session().secret();
+            let wrong = if secret == Guess::MIN { Guess::MAX } else { Guess::MIN };
+
+            for attempt in 1..difficulty.max_attempts() {
+                let (result, hint) = game.play(wrong);
+
+                assert!(!result.is_terminal());
+                assert!(hint.starts_with(&format!("Attempt {}/{} — ", attempt, difficulty.max_attempts())));
+            }
+
+            assert_eq!(game.play(wrong).0, GuessResult::GameOver);
+            assert!(game.session().is_lost());
+            assert_eq!(game.play(secret).0, GuessResult::GameOver);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Hints telling the player whether a guess was too high, too low or correct.
+//! 
+//! # Example
+//! ```
+//! use guessing_utils::Guess;
+//! use guessing_utils::hint::GuessHint;
+//! 
+//! let secret = Guess::new(42).unwrap();
+//! let guess = Guess::new(70).unwrap();
+//! 
+//! assert_eq!(guess.compare_to_target(&secret), GuessHint::TooHigh);
+//! println!("{}", guess.compare_to_target(&secret));
+//! ```
+
+use crate::Guess;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+use core::fmt;
+
+const CORRECT_MESSAGE: &str = "Correct! You guessed the number.";
+const TOO_HIGH_MESSAGE: &str = "Too high! Try a lower number.";
+const TOO_LOW_MESSAGE: &str = "Too low! Try a higher number.";
+
+/// The standard feedback for a guess compared to the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuessHint {
+    /// The guess is exactly the target.
+    Correct,
+    /// The guess is larger than the target.
+    TooHigh,
+    /// The guess is smaller than the target.
+    TooLow,
+}
+
+/// Custom messages for hints, for localizing them or using different vocabulary.
+/// The default messages are the ones of the `Display` impl of `GuessHint`.
+/// # Example
+/// ```
+/// use guessing_utils::hint::{GuessHint, MessageConfig};
+/// 
+/// let config = MessageConfig::default().too_high("Trop grand !").too_low("Trop petit !").correct("Bravo !");
+/// 
+/// assert_eq!(GuessHint::TooHigh.format_with(&config), "Trop grand !");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MessageConfig {
+    /// The message shown when the guess is larger than the target.
+    pub too_high: String,
+    /// The message shown when the guess is smaller than the target.
+    pub too_low: String,
+    /// The message shown when the guess is exactly the target.
+    pub correct: String,
+}
+
+impl MessageConfig {
+    /// Sets the message shown when the guess is larger than the target.
+    pub fn too_high(mut self, msg: impl Into<String>) -> Self {
+        self.too_high = msg.into();
+        self
+    }
+
+    /// Sets the message shown when the guess is smaller than the target.
+    pub fn too_low(mut self, msg: impl Into<String>) -> Self {
+        self.too_low = msg.into();
+        self
+    }
+
+    /// Sets the message shown when the guess is exactly the target.
+    pub fn correct(mut self, msg: impl Into<String>) -> Self {
+        self.correct = msg.into();
+        self
+    }
+}
+
+impl Default for MessageConfig {
+    fn default() -> Self {
+        MessageConfig {
+            too_high: TOO_HIGH_MESSAGE.to_string(),
+            too_low: TOO_LOW_MESSAGE.to_string(),
+            correct: CORRECT_MESSAGE.to_string(),
+        }
+    }
+}
+
+impl GuessHint {
+    /// Checks if the guess was correct.
+    /// # Example
+    /// ```
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// assert!(GuessHint::Correct.is_correct());
+    /// assert!(!GuessHint::TooLow.is_correct());
+    /// ```
+    pub fn is_correct(&self) -> bool {
+        *self == GuessHint::Correct
+    }
+
+    /// Checks if the hint ends the game, which only happens when the guess was correct.
+    /// # Example
+    /// ```
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// assert!(GuessHint::Correct.is_terminal());
+    /// assert!(!GuessHint::TooHigh.is_terminal());
+    /// ```
+    pub fn is_terminal(&self) -> bool {
+        self.is_correct()
+    }
+
+    /// Converts the hint to the ordering of the guess relative to the target.
+    /// # Example
+    /// ```
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// use std::cmp::Ordering;
+    /// 
+    /// assert_eq!(GuessHint::TooHigh.to_ordering(), Ordering::Greater);
+    /// ```
+    pub fn to_ordering(&self) -> Ordering {
+        match self {
+            GuessHint::Correct => Ordering::Equal,
+            GuessHint::TooHigh => Ordering::Greater,
+            GuessHint::TooLow => Ordering::Less,
+        }
+    }
+
+    /// Gets an arrow pointing where the next guess should go.
+    /// # Example
+    /// ```
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// assert_eq!(GuessHint::TooLow.to_arrow(), "↑");
+    /// assert_eq!(GuessHint::TooHigh.to_arrow(), "↓");
+    /// ```
+    pub fn to_arrow(&self) -> &'static str {
+        match self {
+            GuessHint::Correct => "✓",
+            GuessHint::TooHigh => "↓",
+            GuessHint::TooLow => "↑",
+        }
+    }
+
+    /// Gets the comparison symbol of the guess relative to the target.
+    /// # Example
+    /// ```
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// assert_eq!(GuessHint::TooLow.to_symbol(), '<');
+    /// assert_eq!(GuessHint::Correct.to_symbol(), '=');
+    /// ```
+    pub fn to_symbol(&self) -> char {
+        match self {
+            GuessHint::Correct => '=',
+            GuessHint::TooHigh => '>',
+            GuessHint::TooLow => '<',
+        }
+    }
+
+    /// Gets the direction the next guess should move in,
+    /// `1` for higher, `-1` for lower and `None` when the guess was correct.
+    /// # Example
+    /// ```
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// assert_eq!(GuessHint::TooLow.direction(), Some(1));
+    /// assert_eq!(GuessHint::Correct.direction(), None);
+    /// ```
+    pub fn direction(&self) -> Option<i32> {
+        match self {
+            GuessHint::Correct => None,
+            GuessHint::TooHigh => Some(-1),
+            GuessHint::TooLow => Some(1),
+        }
+    }
+
+    /// Describes the hint together with the guess and the attempt it was made on.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// let guess = Guess::new(70).unwrap();
+    /// 
+    /// assert_eq!(GuessHint::TooHigh.verbose_message(&guess, 3), "Attempt 3: your guess of 70 was too high.");
+    /// ```
+    pub fn verbose_message(&self, guess: &Guess, attempts: u32) -> String {
+        let verdict = match self {
+            GuessHint::Correct => "correct!",
+            GuessHint::TooHigh => "too high.",
+            GuessHint::TooLow => "too low.",
+        };
+
+        format!("Attempt {}: your guess of {} was {}", attempts, guess, verdict)
+    }
+
+    /// Describes the hint using the messages of the config.
+    /// # Example
+    /// ```
+    /// use guessing_utils::hint::{GuessHint, MessageConfig};
+    /// 
+    /// let config = MessageConfig::default().correct("You got it!");
+    /// 
+    /// assert_eq!(GuessHint::Correct.format_with(&config), "You got it!");
+    /// assert_eq!(GuessHint::TooLow.format_with(&config), GuessHint::TooLow.to_string());
+    /// ```
+    pub fn format_with(&self, config: &MessageConfig) -> String {
+        let text = match self {
+            GuessHint::Correct => &config.correct,
+            GuessHint::TooHigh => &config.too_high,
+            GuessHint::TooLow => &config.too_low,
+        };
+
+        text.clone()
+    }
+}
+
+impl fmt::Display for GuessHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            GuessHint::Correct => CORRECT_MESSAGE,
+            GuessHint::TooHigh => TOO_HIGH_MESSAGE,
+            GuessHint::TooLow => TOO_LOW_MESSAGE,
+        };
+
+        f.pad(text)
+    }
+}
+
+impl From<Ordering> for GuessHint {
+    fn from(ordering: Ordering) -> Self {
+        match ordering {
+            Ordering::Equal => GuessHint::Correct,
+            Ordering::Greater => GuessHint::TooHigh,
+            Ordering::Less => GuessHint::TooLow,
+        }
+    }
+}
+
+impl From<GuessHint> for Ordering {
+    fn from(hint: GuessHint) -> Self {
+        hint.to_ordering()
+    }
+}
+
+impl PartialEq<Ordering> for GuessHint {
+    fn eq(&self, other: &Ordering) -> bool {
+        self.to_ordering() == *other
+    }
+}
+
+impl Guess {
+    /// Compares the guess to the target, producing the hint to show the player.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// 
+    /// assert_eq!(Guess::new(10).unwrap().compare_to_target(&secret), GuessHint::TooLow);
+    /// assert_eq!(secret.compare_to_target(&secret), GuessHint::Correct);
+    /// ```
+    pub fn compare_to_target(&self, target: &Guess) -> GuessHint {
+        self.cmp(target).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_to_target_test() {
+        let secret = Guess::new(42).unwrap();
+
+        assert_eq!(Guess::new(70).unwrap().compare_to_target(&secret), GuessHint::TooHigh);
+        assert_eq!(Guess::new(10).unwrap().compare_to_target(&secret), GuessHint::TooLow);
+        assert_eq!(secret.compare_to_target(&secret), GuessHint::Correct);
+        assert!(secret.compare_to_target(&secret).is_correct());
+        assert!(secret.compare_to_target(&secret).is_terminal());
+        assert!(!Guess::MAX.compare_to_target(&secret).is_terminal());
+    }
+
+    #[test]
+    fn ordering_conversion_test() {
+        let secret = Guess::new(42).unwrap();
+
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+            let hint = guess.compare_to_target(&secret);
+            let ordering = guess.cmp(&secret);
+
+            assert_eq!(hint.to_ordering(), ordering);
+            assert_eq!(Ordering::from(hint), ordering);
+            assert_eq!(GuessHint::from(ordering), hint);
+            assert!(hint == ordering);
+        }
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(GuessHint::TooHigh.to_string(), "Too high! Try a lower number.");
+        assert_eq!(GuessHint::TooLow.to_string(), "Too low! Try a higher number.");
+        assert_eq!(GuessHint::Correct.to_string(), "Correct! You guessed the number.");
+    }
+
+    #[test]
+    fn symbols_test() {
+        assert_eq!(GuessHint::Correct.to_arrow(), "✓");
+        assert_eq!(GuessHint::TooHigh.to_arrow(), "↓");
+        assert_eq!(GuessHint::TooLow.to_arrow(), "↑");
+        assert_eq!(GuessHint::Correct.to_symbol(), '=');
+        assert_eq!(GuessHint::TooHigh.to_symbol(), '>');
+        assert_eq!(GuessHint::TooLow.to_symbol(), '<');
+    }
+
+    #[test]
+    fn direction_test() {
+        assert_eq!(GuessHint::Correct.direction(), None);
+        assert_eq!(GuessHint::TooHigh.direction(), Some(-1));
+        assert_eq!(GuessHint::TooLow.direction(), Some(1));
+
+        // Moving in the hinted direction reverses the ordering of the guess relative to the target.
+        for hint in [GuessHint::Correct, GuessHint::TooHigh, GuessHint::TooLow] {
+            let ordering_sign = hint.to_ordering() as i32;
+
+            assert_eq!(hint.direction().unwrap_or(0), -ordering_sign);
+        }
+    }
+
+    #[test]
+    fn verbose_message_test() {
+        let guess = Guess::new(70).unwrap();
+
+        assert_eq!(GuessHint::TooHigh.verbose_message(&guess, 3), "Attempt 3: your guess of 70 was too high.");
+        assert_eq!(GuessHint::TooLow.verbose_message(&guess, 1), "Attempt 1: your guess of 70 was too low.");
+        assert_eq!(GuessHint::Correct.verbose_message(&guess, 7), "Attempt 7: your guess of 70 was correct!");
+    }
+
+    #[test]
+    fn format_with_test() {
+        let hints = [GuessHint::Correct, GuessHint::TooHigh, GuessHint::TooLow];
+
+        let default = MessageConfig::default();
+        for hint in hints {
+            assert_eq!(hint.format_with(&default), hint.to_string());
+        }
+
+        let custom = MessageConfig::default().too_high("Lower!").too_low(String::from("Higher!")).correct("Yes!");
+        let expected = ["Yes!", "Lower!", "Higher!"];
+        for (hint, expected) in hints.into_iter().zip(expected) {
+            assert_eq!(hint.format_with(&custom), expected);
+        }
+
+        assert_eq!(MessageConfig::default().too_low("Higher!").too_high, default.too_high);
+    }
+}
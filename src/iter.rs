@@ -0,0 +1,151 @@
+//! A lazy iterator over valid guess values.
+
+use crate::err::GuessError;
+use crate::{Guess, GuessRange, GUESS_RANGE_MAX, GUESS_RANGE_MIN};
+
+use core::iter::FusedIterator;
+
+/// An iterator over consecutive guess values, created by `all_guesses()` or `guesses_in_range(min, max)`.
+/// # Example
+/// ```
+/// use guessing_utils::all_guesses;
+/// 
+/// let evens = all_guesses().filter(|g| g.is_even()).count();
+/// 
+/// assert_eq!(evens, 51);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GuessIter {
+    current: i32,
+    end: i32,
+}
+
+impl Iterator for GuessIter {
+    type Item = Guess;
+
+    fn next(&mut self) -> Option<Guess> {
+        if self.current > self.end {
+            return None;
+        }
+
+        let guess = Guess { val: self.current };
+        self.current += 1;
+        Some(guess)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.current + 1).max(0) as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for GuessIter {
+    fn next_back(&mut self) -> Option<Guess> {
+        if self.current > self.end {
+            return None;
+        }
+
+        let guess = Guess { val: self.end };
+        self.end -= 1;
+        Some(guess)
+    }
+}
+
+impl ExactSizeIterator for GuessIter {}
+
+impl FusedIterator for GuessIter {}
+
+/// Iterates over every valid guess, from `0` to `100`.
+/// # Example
+/// ```
+/// use guessing_utils::{all_guesses, Guess};
+/// 
+/// assert_eq!(all_guesses().len(), 101);
+/// assert_eq!(all_guesses().next_back(), Some(Guess::MAX));
+/// ```
+pub fn all_guesses() -> GuessIter {
+    GuessIter { current: GUESS_RANGE_MIN, end: GUESS_RANGE_MAX }
+}
+
+/// Iterates over every guess in the inclusive range from `min` to `max`.
+/// A `RangeError` will be returned if either of the bounds is outside (0..101) range,
+/// or an `InvertedRange` error if `max` is smaller than `min`.
+/// # Example
+/// ```
+/// use guessing_utils::guesses_in_range;
+/// 
+/// assert_eq!(guesses_in_range(50, 60).unwrap().count(), 11);
+/// assert!(guesses_in_range(60, 50).is_err());
+/// ```
+pub fn guesses_in_range(min: i32, max: i32) -> Result<GuessIter, GuessError> {
+    Ok(GuessRange::new(min, max)?.iter())
+}
+
+impl GuessRange {
+    /// Iterates over every value in the range in ascending order.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// assert_eq!(GuessRange::from_full_range().iter().count(), 101);
+    /// ```
+    pub fn iter(&self) -> GuessIter {
+        GuessIter { current: self.min().val, end: self.max().val }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::err::GuessRangeError;
+
+    #[test]
+    fn all_guesses_test() {
+        assert_eq!(all_guesses().count(), 101);
+        assert_eq!(all_guesses().len(), 101);
+        assert_eq!(all_guesses().next(), Some(Guess::MIN));
+        assert_eq!(all_guesses().rev().collect::<Vec<_>>()[0], Guess::MAX);
+        assert!(all_guesses().zip(0..).all(|(g, val)| g.get() == val));
+    }
+
+    #[test]
+    fn double_ended_test() {
+        let mut iter = guesses_in_range(1, 4).unwrap();
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next().map(|g| g.get()), Some(1));
+        assert_eq!(iter.next_back().map(|g| g.get()), Some(4));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next_back().map(|g| g.get()), Some(3));
+        assert_eq!(iter.next().map(|g| g.get()), Some(2));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn fused_test() {
+        let mut iter = all_guesses();
+        iter.by_ref().for_each(drop);
+
+        for _ in 0..5 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+            assert_eq!(iter.len(), 0);
+        }
+
+        let mut single = guesses_in_range(0, 0).unwrap();
+        assert_eq!(single.next_back(), Some(Guess::MIN));
+        assert_eq!(single.next(), None);
+        assert_eq!(single.next_back(), None);
+    }
+
+    #[test]
+    fn guesses_in_range_test() {
+        assert_eq!(guesses_in_range(50, 60).unwrap().collect::<Vec<_>>().len(), 11);
+        assert_eq!(guesses_in_range(42, 42).unwrap().len(), 1);
+        assert_eq!(guesses_in_range(60, 50).unwrap_err(), GuessError::InvertedRange { min: 60, max: 50 });
+        assert_eq!(guesses_in_range(-1, 50).unwrap_err(), GuessError::RangeError(GuessRangeError(-1)));
+        assert_eq!(guesses_in_range(0, 101).unwrap_err(), GuessError::RangeError(GuessRangeError(101)));
+    }
+}
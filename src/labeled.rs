@@ -0,0 +1,145 @@
+//! Guesses tagged with the name of the player who made them.
+
+use crate::err::GuessRangeError;
+use crate::Guess;
+
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::Deref;
+
+/// A guess paired with a label, usually the name of the player who made it.
+/// Dereferences to the guess, so every `Guess` method can be called on it directly.
+/// # Example
+/// ```
+/// use guessing_utils::LabeledGuess;
+/// 
+/// let mut guesses = vec![
+///     LabeledGuess::new("Carol", 70).unwrap(),
+///     LabeledGuess::new("Bob", 42).unwrap(),
+///     LabeledGuess::new("Alice", 70).unwrap(),
+/// ];
+/// guesses.sort();
+/// 
+/// let names: Vec<&str> = guesses.iter().map(|g| g.label.as_str()).collect();
+/// 
+/// assert_eq!(names, ["Bob", "Alice", "Carol"]);
+/// assert_eq!(guesses[0].to_string(), "Bob: 42");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LabeledGuess {
+    /// The name of the player who made the guess.
+    pub label: String,
+    /// The guess itself.
+    pub guess: Guess,
+}
+
+impl LabeledGuess {
+    /// Creates a new labeled guess.
+    /// An error will be returned if the provided number is outside (0..101) range.
+    pub fn new(label: impl Into<String>, val: i32) -> Result<LabeledGuess, GuessRangeError> {
+        Ok(LabeledGuess { label: label.into(), guess: Guess::new(val)? })
+    }
+
+    /// Formats the label and the guess as a comma-separated row.
+    /// Labels containing commas, quotes or line breaks are quoted.
+    /// # Example
+    /// ```
+    /// use guessing_utils::LabeledGuess;
+    /// 
+    /// assert_eq!(LabeledGuess::new("Alice", 42).unwrap().to_csv_row(), "Alice,42");
+    /// assert_eq!(LabeledGuess::new("Doe, John", 7).unwrap().to_csv_row(), "\"Doe, John\",7");
+    /// ```
+    pub fn to_csv_row(&self) -> String {
+        if self.label.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\",{}", self.label.replace('"', "\"\""), self.guess)
+        } else {
+            format!("{},{}", self.label, self.guess)
+        }
+    }
+}
+
+impl Deref for LabeledGuess {
+    type Target = Guess;
+
+    fn deref(&self) -> &Guess {
+        &self.guess
+    }
+}
+
+/// Orders by the guess first, breaking ties alphabetically by the label.
+impl Ord for LabeledGuess {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.guess.cmp(&other.guess).then_with(|| self.label.cmp(&other.label))
+    }
+}
+
+impl PartialOrd for LabeledGuess {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for LabeledGuess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!("{}: {}", self.label, self.guess))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labeled_guess_new_test() {
+        let guess = LabeledGuess::new("Alice", 42).unwrap();
+
+        assert_eq!(guess.label, "Alice");
+        assert_eq!(guess.guess, Guess::new(42).unwrap());
+        assert_eq!(LabeledGuess::new(String::from("Bob"), 0).unwrap().label, "Bob");
+        assert_eq!(LabeledGuess::new("Alice", 101), Err(GuessRangeError(101)));
+        assert_eq!(LabeledGuess::new("Alice", -1), Err(GuessRangeError(-1)));
+    }
+
+    #[test]
+    fn labeled_guess_display_test() {
+        assert_eq!(LabeledGuess::new("PlayerName", 42).unwrap().to_string(), "PlayerName: 42");
+        assert_eq!(format!("{:>12}|", LabeledGuess::new("Bob", 7).unwrap()), "      Bob: 7|");
+        assert_eq!(LabeledGuess::new("PlayerName", 42).unwrap().to_csv_row(), "PlayerName,42");
+        assert_eq!(LabeledGuess::new("say \"hi\"", 1).unwrap().to_csv_row(), "\"say \"\"hi\"\"\",1");
+    }
+
+    #[test]
+    fn labeled_guess_sort_test() {
+        let mut guesses = [
+            LabeledGuess::new("Dave", 90).unwrap(),
+            LabeledGuess::new("Carol", 42).unwrap(),
+            LabeledGuess::new("Alice", 42).unwrap(),
+            LabeledGuess::new("Bob", 10).unwrap(),
+            LabeledGuess::new("Bob", 42).unwrap(),
+        ];
+        guesses.sort();
+
+        let rows: Vec<String> = guesses.iter().map(|g| g.to_csv_row()).collect();
+        assert_eq!(rows, ["Bob,10", "Alice,42", "Bob,42", "Carol,42", "Dave,90"]);
+
+        assert_ne!(LabeledGuess::new("Alice", 42).unwrap(), LabeledGuess::new("Bob", 42).unwrap());
+        assert_ne!(LabeledGuess::new("Alice", 42).unwrap(), LabeledGuess::new("Alice", 43).unwrap());
+    }
+
+    #[test]
+    fn labeled_guess_deref_test() {
+        let labeled = LabeledGuess::new("Alice", 40).unwrap();
+        let secret = Guess::new(42).unwrap();
+
+        assert_eq!(labeled.get(), 40);
+        assert_eq!(labeled.abs_diff(&secret), 2);
+        assert_eq!(*labeled, Guess::new(40).unwrap());
+
+        fn takes_guess(guess: &Guess) -> i32 {
+            guess.get()
+        }
+        assert_eq!(takes_guess(&labeled), 40);
+    }
+}
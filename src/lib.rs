@@ -1,8 +1,120 @@
 //! # Guessing utilities
 //! This crate is ideal for writing programs for guessing numbers in range (0..101).
 //! It provides a handful of utilities made for various cases.
+//! 
+//! The core of the crate is `Guess`, a cheap copyable validated integer wrapper:
+//! it can be passed around by value just like an `i32`, while always holding a value in (0..101) range.
+//! 
+//! # Example
+//! A complete guessing game, reading the guesses from the standard input:
+//! ```no_run
+//! use guessing_utils::{Guess, GuessSession};
+//! 
+//! let mut session = GuessSession::new_random(7);
+//! 
+//! while !session.is_over() {
+//!     match Guess::scan_from_stdin_prompt("Please input your guess: ") {
+//!         Ok(guess) => println!("{}", session.attempt(guess)),
+//!         Err(err) => println!("Invalid guess: {}", err),
+//!     }
+//! }
+//! 
+//! println!("The number was {}.", session.secret());
+//! ```
+//! 
+//! # Guess literals
+//! The `guess!` macro creates a guess from a constant, rejecting out-of-range values at compile time:
+//! ```
+//! use guessing_utils::prelude::*;
+//! 
+//! const SECRET: Guess = guess!(42);
+//! ```
+//! 
+//! # `no_std` support
+//! The crate builds without the standard library when the default `std` feature is disabled,
+//! as long as an allocator is available. The items which need an operating system are unavailable then:
+//! every function drawing from `rand::thread_rng()` (such as `gen_random()`, `GuessRange::random()`,
+//! `GuessSession::new_random()` and `GuessSession::reset()`), `GuessGame` and `GuessSet`.
+//! The `_with_rng` and seeded variants of the random functions work everywhere.
 
-use rand::Rng;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod cmp;
+mod convert;
+mod diff;
+mod filter;
+mod float;
+#[cfg(feature = "std")]
+mod game;
+pub mod hint;
+mod iter;
+mod labeled;
+mod list;
+pub mod math;
+mod ops;
+mod position;
+pub mod prelude;
+mod random;
+mod range;
+mod ranged;
+mod result;
+pub mod scoring;
+mod session;
+pub mod stats;
+pub mod strategy;
+pub mod temperature;
+mod text;
+
+pub use diff::GuessDiff;
+pub use filter::GuessFilter;
+#[cfg(feature = "std")]
+pub use game::{Difficulty, GuessGame};
+pub use iter::{all_guesses, guesses_in_range, GuessIter};
+pub use labeled::LabeledGuess;
+pub use list::GuessList;
+pub use random::{
+    gen_random_excluding_range_with_rng, gen_random_excluding_with_rng, gen_random_n_with_rng,
+    gen_random_range_with_rng, gen_random_seeded, gen_random_seeded_sequence, gen_random_unique_batch_with_rng,
+    gen_random_weighted_with_rng, gen_random_with_rng,
+};
+#[cfg(feature = "std")]
+pub use random::{
+    gen_random, gen_random_batch, gen_random_excluding, gen_random_excluding_range, gen_random_range,
+    gen_random_unique_batch, gen_random_weighted,
+};
+#[cfg(feature = "gaussian")]
+pub use random::gen_random_gaussian_with_rng;
+#[cfg(all(feature = "gaussian", feature = "std"))]
+pub use random::gen_random_gaussian;
+pub use range::GuessRange;
+pub use ranged::{RangedGuess, StandardGuess};
+pub use result::GuessResult;
+pub use session::{GuessSession, GuessSessionConfig, HintMode};
+pub use stats::GuessStats;
+pub use text::MORSE_DIGITS;
+
+use alloc::format;
+
+/// The smallest value a guess can hold.
+pub const GUESS_RANGE_MIN: i32 = 0;
+
+/// The largest value a guess can hold.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GUESS_RANGE_MAX, GUESS_RANGE_MIN};
+/// 
+/// let input = 150;
+/// 
+/// if Guess::new(input).is_err() {
+///     println!("Please enter a number from {} to {}.", GUESS_RANGE_MIN, GUESS_RANGE_MAX);
+/// }
+/// ```
+pub const GUESS_RANGE_MAX: i32 = 100;
+
+/// The number of distinct values a guess can hold.
+pub const GUESS_RANGE_SIZE: usize = 101;
 
 /// Used for creating a guess. This is a good alternative to storing a guessed number,
 /// which is much safer and comfortable to use compared to a primitive number.
@@ -18,31 +130,281 @@ use rand::Rng;
 /// 
 /// # Comfort
 /// The object implements equality and comparing checks to use with other guesses,
-/// therefore not having to rely on `value()` function every time.
-#[derive(Eq, Debug)]
+/// therefore not having to rely on `get()` function every time.
+/// 
+/// # Copy semantics
+/// The object is `Copy`, just like the integer it wraps, so passing it by value
+/// does not consume it. A copy of a valid guess is always a valid guess.
+#[derive(Eq, Debug, Clone, Copy)]
 pub struct Guess {
     val: i32, // i32 instead of u32 for future capabilities
 }
 
+/// The guess holding `0`, the smallest valid value.
+pub const GUESS_ZERO: Guess = Guess::new_const(GUESS_RANGE_MIN);
+
+/// The guess holding `100`, the largest valid value.
+pub const GUESS_MAX_VAL: Guess = Guess::new_const(GUESS_RANGE_MAX);
+
+/// The guess holding `50`, the value in the middle of the range.
+pub const GUESS_MID: Guess = Guess::new_const(50);
+
+/// Creates a guess from a constant expression, checking the range at compile time.
+/// Available from the prelude, `use guessing_utils::prelude::*;`.
+/// # Example
+/// The macro works for constants as well as in regular code:
+/// ```
+/// use guessing_utils::prelude::*;
+/// 
+/// const SECRET: Guess = guess!(42);
+/// 
+/// let bounds = [guess!(0), guess!(50), guess!(100)];
+/// 
+/// assert_eq!(SECRET, Guess::new(42).unwrap());
+/// assert_eq!(bounds, [Guess::MIN, Guess::MIDPOINT, Guess::MAX]);
+/// ```
+/// Out-of-range values are rejected by the compiler:
+/// ```compile_fail
+/// use guessing_utils::guess;
+/// 
+/// let too_large = guess!(101);
+/// ```
+#[macro_export]
+macro_rules! guess {
+    ($val:expr) => {{
+        const GUESS: $crate::Guess = $crate::Guess::new_const($val);
+        GUESS
+    }};
+}
+
 /// Custom-written error handling.
 pub mod err {
-    use std::fmt;
+    use alloc::string::String;
+    use core::fmt;
 
     /// Used when the provided argument is outside the required (0..101) range.
     /// Usually returned by `Guess::new(val: i32)` function when an invalid input is provided.
-    #[derive(Debug, Clone)]
-    pub struct GuessRangeError;
+    /// The rejected value is stored inside the error.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let err = Guess::new(150).unwrap_err();
+    /// 
+    /// assert_eq!(err.value(), 150);
+    /// println!("Your input {} is too large; please enter 0-100.", err.value());
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct GuessRangeError(pub i32);
+
+    impl GuessRangeError {
+        /// Gets the rejected value which caused the error.
+        pub fn value(&self) -> i32 {
+            self.0
+        }
+    }
 
     impl fmt::Display for GuessRangeError {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "The guess value was out of 0-100 range")
+            write!(f, "{} is outside 0-100", self.0)
         }
     }
 
-    impl std::error::Error for GuessRangeError {}
+    impl core::error::Error for GuessRangeError {}
+
+    /// Used when a guess could not be created from the provided input.
+    /// Returned by `Guess::parse(val: &str)`, it tells apart a malformed input
+    /// from a well-formed number which is outside the (0..101) range.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum GuessError {
+        /// The input could not be parsed as an integer.
+        ParseError(core::num::ParseIntError),
+        /// The input was a valid integer, but outside the (0..101) range.
+        RangeError(GuessRangeError),
+        /// The input character was not a decimal digit.
+        InvalidDigit(char),
+        /// A guess was divided by zero.
+        DivisionByZero,
+        /// The input was not a valid textual representation of a guess.
+        /// The rejected input is stored inside the error.
+        InvalidFormat(String),
+        /// More distinct values were requested than the range can provide.
+        InsufficientValues { requested: usize, available: usize },
+        /// The value was outside the custom range from `min` to `max` of a `RangedGuess`.
+        OutOfBounds { value: i32, min: i32, max: i32 },
+        /// The lower bound of a range was greater than its upper bound.
+        InvertedRange { min: i32, max: i32 },
+    }
+
+    impl fmt::Display for GuessError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                GuessError::ParseError(err) => write!(f, "parse error: {}", err),
+                GuessError::RangeError(err) => write!(f, "range error: {}", err),
+                GuessError::InvalidDigit(c) => write!(f, "invalid digit: {:?}", c),
+                GuessError::DivisionByZero => write!(f, "division by zero"),
+                GuessError::InvalidFormat(input) => write!(f, "invalid format: {:?}", input),
+                GuessError::InsufficientValues { requested, available } => {
+                    write!(f, "requested {} distinct values, but only {} are available", requested, available)
+                }
+                GuessError::OutOfBounds { value, min, max } => write!(f, "{} is outside {}-{}", value, min, max),
+                GuessError::InvertedRange { min, max } => write!(f, "the range {}-{} is inverted", min, max),
+            }
+        }
+    }
+
+    /// Exposes the wrapped error of `ParseError` and `RangeError` as the source,
+    /// so error chain tools can report the underlying cause.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// use std::error::Error;
+    /// 
+    /// let err = Guess::parse("abc").unwrap_err();
+    /// let source = err.source().unwrap();
+    /// 
+    /// assert_eq!(source.to_string(), "abc".parse::<i32>().unwrap_err().to_string());
+    /// ```
+    impl core::error::Error for GuessError {
+        fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+            match self {
+                GuessError::ParseError(err) => Some(err),
+                GuessError::RangeError(err) => Some(err),
+                GuessError::InvalidDigit(_)
+                | GuessError::DivisionByZero
+                | GuessError::InvalidFormat(_)
+                | GuessError::InsufficientValues { .. }
+                | GuessError::OutOfBounds { .. }
+                | GuessError::InvertedRange { .. } => None,
+            }
+        }
+    }
+
+    impl From<core::num::ParseIntError> for GuessError {
+        fn from(err: core::num::ParseIntError) -> Self {
+            GuessError::ParseError(err)
+        }
+    }
+
+    /// Turns the error into an `io::Error` of kind `InvalidInput`, so it can be returned with `?` from input handling code.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// use std::io;
+    /// 
+    /// fn read_guess(input: &str) -> io::Result<Guess> {
+    ///     Ok(Guess::new(input.trim().parse().map_err(|_| io::ErrorKind::InvalidData)?)?)
+    /// }
+    /// 
+    /// assert_eq!(read_guess("150").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    /// ```
+    #[cfg(feature = "std")]
+    impl From<GuessRangeError> for std::io::Error {
+        fn from(err: GuessRangeError) -> Self {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, err)
+        }
+    }
+
+    /// Turns the error into an `io::Error` of kind `InvalidInput`, so it can be returned with `?` from input handling code.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// use std::io;
+    /// 
+    /// fn read_guess(input: &str) -> io::Result<Guess> {
+    ///     Ok(Guess::parse(input)?)
+    /// }
+    /// 
+    /// assert_eq!(read_guess("abc").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    /// ```
+    #[cfg(feature = "std")]
+    impl From<GuessError> for std::io::Error {
+        fn from(err: GuessError) -> Self {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, err)
+        }
+    }
+
+    impl From<GuessRangeError> for GuessError {
+        fn from(err: GuessRangeError) -> Self {
+            GuessError::RangeError(err)
+        }
+    }
+
+    /// Used when a slice of weights cannot be used to sample a random guess.
+    /// Returned by `gen_random_weighted(weights: &[f64])`.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum WeightError {
+        /// The slice did not hold exactly one weight per valid value.
+        /// The length of the rejected slice is stored inside the error.
+        WrongLength(usize),
+        /// A weight was negative, NaN or infinite.
+        InvalidWeight { index: usize, weight: f64 },
+        /// Every weight was zero, so no value can be chosen.
+        AllWeightsZero,
+    }
+
+    impl fmt::Display for WeightError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                WeightError::WrongLength(len) => write!(f, "expected 101 weights, got {}", len),
+                WeightError::InvalidWeight { index, weight } => {
+                    write!(f, "invalid weight {} for value {}", weight, index)
+                }
+                WeightError::AllWeightsZero => write!(f, "all weights are zero"),
+            }
+        }
+    }
+
+    impl core::error::Error for WeightError {}
+
+    /// Used when a session cannot be decoded from bytes.
+    /// Returned by `GuessSession::deserialize_from_bytes(bytes: &[u8])`.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum DeserializeError {
+        /// The input ended early, holding only `actual` of the `expected` bytes.
+        Truncated { expected: usize, actual: usize },
+        /// A byte holding a guess was outside (0..101) range.
+        InvalidGuess(u8),
+        /// The byte holding the won flag was neither `0` nor `1`.
+        InvalidFlag(u8),
+        /// The input continued after the encoded session, holding this many extra bytes.
+        TrailingBytes(usize),
+        /// The decoded fields contradict each other, like more attempts used than allowed
+        /// or a won flag not matching the history.
+        Inconsistent,
+    }
+
+    impl fmt::Display for DeserializeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                DeserializeError::Truncated { expected, actual } => {
+                    write!(f, "expected {} bytes, got {}", expected, actual)
+                }
+                DeserializeError::InvalidGuess(byte) => write!(f, "invalid guess byte: {}", byte),
+                DeserializeError::InvalidFlag(byte) => write!(f, "invalid flag byte: {}", byte),
+                DeserializeError::TrailingBytes(count) => write!(f, "{} unexpected trailing bytes", count),
+                DeserializeError::Inconsistent => write!(f, "the decoded fields contradict each other"),
+            }
+        }
+    }
+
+    impl core::error::Error for DeserializeError {}
 }
 
 impl Guess {
+    /// The guess holding the smallest valid value, `0`.
+    pub const MIN: Guess = Guess::new_const(GUESS_RANGE_MIN);
+
+    /// The guess holding the largest valid value, `100`.
+    pub const MAX: Guess = Guess::new_const(GUESS_RANGE_MAX);
+
+    /// The guess holding the value in the middle of the range, `50`.
+    pub const MIDPOINT: Guess = Guess::new_const((GUESS_RANGE_MIN + GUESS_RANGE_MAX) / 2);
+
     /// Creates a new `Guess` object.
     /// An error will be returned if the provided number was outside (0..101) range.
     /// # Example
@@ -54,91 +416,423 @@ impl Guess {
     /// 
     /// let guess = match guess {
     ///     Ok(val) => val,
-    ///     Err(err) => panic!("Provided value was outside the range!"),
+    ///     Err(err) => panic!("Provided value {} was outside the range!", err.value()),
     /// };
     /// ```
     pub fn new(val: i32) -> Result<Guess, err::GuessRangeError> {
-        if val < 0 || val > 100 {
-            return Err(err::GuessRangeError);
+        if !(GUESS_RANGE_MIN..=GUESS_RANGE_MAX).contains(&val) {
+            return Err(err::GuessRangeError(val));
         }
 
         Ok(Guess { val })
     }
 
+    /// Creates a new `Guess` object in a `const` context.
+    /// Panics if the provided number is outside (0..101) range,
+    /// which turns into a compile-time error when used for a constant.
+    /// # Panics
+    /// Panics when `val` is outside (0..101) range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// const GOOD: Guess = Guess::new_const(42);
+    /// assert_eq!(GOOD.get(), 42);
+    /// ```
+    /// Out-of-range constants are rejected by the compiler:
+    /// ```compile_fail
+    /// use guessing_utils::Guess;
+    /// 
+    /// const BAD: Guess = Guess::new_const(101);
+    /// ```
+    pub const fn new_const(val: i32) -> Guess {
+        if val < GUESS_RANGE_MIN || val > GUESS_RANGE_MAX {
+            panic!("Guess value out of range");
+        }
+
+        Guess { val }
+    }
+
+    /// Creates a new `Guess` object without checking if the provided number is in (0..101) range.
+    /// Useful in hot paths where the value is already known to be valid,
+    /// for example after a modulo operation.
+    /// Debug builds still verify the range with an assertion.
+    /// # Safety
+    /// The caller must guarantee that `0 <= val <= 100`.
+    /// Other functions of the crate rely on this invariant,
+    /// so an out-of-range guess may cause incorrect results or panics.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let raw = 12345 % 101; // always in range
+    /// let guess = unsafe { Guess::new_unchecked(raw) };
+    /// 
+    /// assert_eq!(guess.get(), 23);
+    /// ```
+    pub unsafe fn new_unchecked(val: i32) -> Guess {
+        debug_assert!((GUESS_RANGE_MIN..=GUESS_RANGE_MAX).contains(&val));
+
+        Guess { val }
+    }
+
+    /// Creates a new `Guess` object, wrapping the provided number around (0..101) range.
+    /// The range is treated as a ring of 101 elements, so `-1` becomes `100` and `101` becomes `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new_modular(-1), Guess::MAX);
+    /// assert_eq!(Guess::new_modular(101), Guess::MIN);
+    /// assert_eq!(Guess::new_modular(142).get(), 41);
+    /// ```
+    pub fn new_modular(val: i32) -> Guess {
+        Guess { val: val.rem_euclid(GUESS_RANGE_SIZE as i32) }
+    }
+
+    /// Creates a new `Guess` object, clamping the provided number into (0..101) range.
+    /// Values below 0 become 0 and values above 100 become 100, so this never fails.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new_clamped(150), Guess::MAX);
+    /// assert_eq!(Guess::new_clamped(-20), Guess::MIN);
+    /// assert_eq!(Guess::new_clamped(42), Guess::new(42).unwrap());
+    /// ```
+    pub fn new_clamped(val: i32) -> Guess {
+        Guess { val: val.clamp(GUESS_RANGE_MIN, GUESS_RANGE_MAX) }
+    }
+
+    /// Creates a new `Guess` object from a floating point number,
+    /// rounding it to the nearest integer before clamping it into (0..101) range.
+    /// Infinities are clamped like any other value, while NaN produces the default guess.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new_clamped_from_f64(41.6), Guess::new(42).unwrap());
+    /// assert_eq!(Guess::new_clamped_from_f64(f64::INFINITY), Guess::MAX);
+    /// assert_eq!(Guess::new_clamped_from_f64(f64::NAN), Guess::default());
+    /// ```
+    pub fn new_clamped_from_f64(val: f64) -> Guess {
+        if val.is_nan() {
+            return Guess::default();
+        }
+
+        let val = float::round(val).clamp(GUESS_RANGE_MIN as f64, GUESS_RANGE_MAX as f64);
+        Guess { val: val as i32 }
+    }
+
+    /// Creates a new `Guess` object, falling back to the smallest guess when the provided number is outside (0..101) range.
+    /// # Warning
+    /// The invalid input is silently discarded, so this is only meant for non-critical contexts like display code.
+    /// Never use it for input received from users, validate that with `Guess::new` or `Guess::parse` instead.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new_or_default(150), Guess::MIN);
+    /// assert_eq!(Guess::new_or_default(42), Guess::new(42).unwrap());
+    /// ```
+    pub fn new_or_default(val: i32) -> Guess {
+        Guess::new(val).unwrap_or_default()
+    }
+
+    /// Creates a new `Guess` object, falling back to the largest guess when the provided number is outside (0..101) range.
+    /// # Warning
+    /// The invalid input is silently discarded, so this is only meant for non-critical contexts like display code.
+    /// Never use it for input received from users, validate that with `Guess::new` or `Guess::parse` instead.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new_or_max(-5), Guess::MAX);
+    /// assert_eq!(Guess::new_or_max(42), Guess::new(42).unwrap());
+    /// ```
+    pub fn new_or_max(val: i32) -> Guess {
+        Guess::new(val).unwrap_or(Guess::MAX)
+    }
+
+    /// Creates a new `Guess` object, clamping the provided number into (0..101) range.
+    /// This is the same as `Guess::new_clamped`.
+    /// # Warning
+    /// The invalid input is silently discarded, so this is only meant for non-critical contexts like display code.
+    /// Never use it for input received from users, validate that with `Guess::new` or `Guess::parse` instead.
+    pub fn new_or_clamped(val: i32) -> Guess {
+        Guess::new_clamped(val)
+    }
+
     /// Creates a new object from parsing the provided string slice.
-    /// Invalid input will produce either a parsing or a range error.
+    /// Invalid input will produce either a parsing or a range error,
+    /// which can be told apart by matching on the returned `GuessError`.
     /// # Example
     /// ```
     /// use guessing_utils::Guess;
+    /// use guessing_utils::err::GuessError;
     /// 
     /// let guess = "57"; // value to parse (input)
     /// 
     /// let guess = match Guess::parse(&guess) {
     ///     Ok(val) => val,
-    ///     Err(err) => panic!("{}", err), // handle the input error
+    ///     Err(GuessError::ParseError(err)) => panic!("Not a number: {}", err),
+    ///     Err(GuessError::RangeError(err)) => panic!("Not in range: {}", err),
+    ///     Err(err) => panic!("{}", err),
     /// };
     /// ```
-    pub fn parse(val: &str) -> Result<Guess, Box<dyn std::error::Error>> {
-        let parsed: i32 = match val.trim().parse() {
-            Ok(val) => val,
-            Err(err) => return Err(err)?,
-        };
+    pub fn parse(val: &str) -> Result<Guess, err::GuessError> {
+        val.parse()
+    }
 
-        match Guess::new(parsed) {
-            Ok(val) => Ok(val),
-            Err(err) => Err(err)?
-        }
+    /// Creates a new object from parsing one line read from the provided reader.
+    /// Reading errors are returned as well as parsing and range errors.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use std::io::Cursor;
+    /// 
+    /// let mut input = Cursor::new("42\n420\n".as_bytes());
+    /// 
+    /// assert_eq!(Guess::scan_from_reader(&mut input).unwrap(), Guess::new(42).unwrap());
+    /// assert!(Guess::scan_from_reader(&mut input).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn scan_from_reader<R: std::io::BufRead>(reader: &mut R) -> Result<Guess, Box<dyn std::error::Error>> {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+
+        Ok(Guess::parse(line.trim())?)
+    }
+
+    /// Creates a new object from parsing one line read from the standard input.
+    #[cfg(feature = "std")]
+    pub fn scan_from_stdin() -> Result<Guess, Box<dyn std::error::Error>> {
+        Guess::scan_from_reader(&mut std::io::stdin().lock())
+    }
+
+    /// Prints the prompt to the standard output, then creates a new object
+    /// from parsing one line read from the standard input.
+    #[cfg(feature = "std")]
+    pub fn scan_from_stdin_prompt(prompt: &str) -> Result<Guess, Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        let mut stdout = std::io::stdout();
+        stdout.write_all(prompt.as_bytes())?;
+        stdout.flush()?;
+
+        Guess::scan_from_stdin()
     }
 
-    /// Gets the value stored in the object.
+    /// Gets a copy of the value stored in the object, the same as `get()`.
     /// # Example
     /// ```
     /// use guessing_utils::Guess;
     /// 
     /// let guess = Guess::new(15).unwrap();
-    /// println!("{}", guess.value()); // 15
+    /// assert_eq!(guess.value() + 1, 16);
     /// ```
-    pub fn value(&self) -> &i32 {
+    pub fn value(&self) -> i32 {
+        self.val
+    }
+
+    /// Gets a reference to the value stored in the object,
+    /// for the rare cases where a reference is needed.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(15).unwrap();
+    /// assert_eq!(guess.value_ref(), &15);
+    /// ```
+    pub fn value_ref(&self) -> &i32 {
         &self.val
     }
+
+    /// Gets a copy of the value stored in the object.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(15).unwrap();
+    /// assert_eq!(guess.get() + 1, 16);
+    /// ```
+    pub fn get(&self) -> i32 {
+        self.val
+    }
+
+    /// Consumes the object, returning the value stored inside of it.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(15).unwrap();
+    /// assert_eq!(guess.into_inner(), 15);
+    /// ```
+    pub fn into_inner(self) -> i32 {
+        self.val
+    }
 }
 
 impl Ord for Guess {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.value().cmp(other.value())
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.val.cmp(&other.val)
     }
 }
 
 impl PartialOrd for Guess {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
 impl PartialEq for Guess {
     fn eq(&self, other: &Self) -> bool {
-        self.value() == other.value()
+        self.val == other.val
+    }
+}
+
+/// Allows parsing a guess with the standard `str::parse` function.
+/// Leading and trailing whitespace is ignored, just like in `Guess::parse(val: &str)`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// let guess: Guess = "42".parse().unwrap();
+/// 
+/// assert_eq!(guess, Guess::new(42).unwrap());
+/// assert!("abc".parse::<Guess>().is_err());
+/// ```
+impl core::str::FromStr for Guess {
+    type Err = err::GuessError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed: i32 = s.trim().parse()?;
+
+        Ok(Guess::new(parsed)?)
+    }
+}
+
+/// The default guess is the minimum valid value, `0`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// let guess: Option<Guess> = None;
+/// 
+/// assert_eq!(guess.unwrap_or_default(), Guess::MIN);
+/// ```
+impl Default for Guess {
+    fn default() -> Self {
+        Guess::MIN
+    }
+}
+
+impl core::hash::Hash for Guess {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.val.hash(state);
+    }
+}
+
+/// Serializes the guess as a plain integer.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Guess {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.val)
+    }
+}
+
+/// Deserializes the guess from a plain integer, rejecting values outside the (0..101) range.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Guess {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let val = <i64 as serde::Deserialize>::deserialize(deserializer)?;
+
+        i32::try_from(val)
+            .ok()
+            .and_then(|val| Guess::new(val).ok())
+            .ok_or_else(|| serde::de::Error::custom(format_args!("{} is outside 0-100", val)))
     }
 }
 
-/// Generates a `Guess` object together with a randomly generated number in (0..101) range put inside the object.
+/// Formats the stored value like a plain integer, so all the standard
+/// width, fill and alignment flags work as usual.
+/// The alternate flag (`{:#}`) produces the decorated `Guess(val)` form instead.
 /// # Example
 /// ```
 /// use guessing_utils::Guess;
-/// use guessing_utils::gen_random;
 /// 
-/// use std::cmp::Ordering;
+/// let guess = Guess::new(42).unwrap();
 /// 
-/// let random_guess = gen_random();
-/// let my_guess = Guess::new(50).unwrap();
+/// assert_eq!(format!("{}", guess), "42");
+/// assert_eq!(format!("{:05}", guess), "00042");
+/// assert_eq!(format!("{:>6}|{:<6}|", guess, guess), "    42|42    |");
+/// assert_eq!(format!("{:#}", guess), "Guess(42)");
+/// assert_eq!(format!("You guessed {} out of {}.", guess, 100), "You guessed 42 out of 100.");
+/// ```
+impl core::fmt::Display for Guess {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.pad(&format!("Guess({})", self.val))
+        } else {
+            core::fmt::Display::fmt(&self.val, f)
+        }
+    }
+}
+
+/// Implements the integer formatting traits by delegating to the inner value.
+macro_rules! impl_fmt_radix {
+    ($($trait:ident),*) => {
+        $(
+            impl core::fmt::$trait for Guess {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::$trait::fmt(&self.val, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_radix!(Binary, Octal, LowerHex, UpperHex);
+
+/// Implements the scientific notation formatting traits by delegating to the value as `f64`.
+macro_rules! impl_fmt_exp {
+    ($($trait:ident),*) => {
+        $(
+            impl core::fmt::$trait for Guess {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::$trait::fmt(&(self.val as f64), f)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt_exp!(LowerExp, UpperExp);
+
+/// A set of unique guesses, handy for tracking which values were already guessed.
+#[cfg(feature = "std")]
+pub type GuessSet = std::collections::HashSet<Guess>;
+
+/// Creates a `GuessSet` containing every value in the provided inclusive range.
+/// An error will be returned if either of the bounds is outside (0..101) range.
+/// An empty set is returned when `min` is greater than `max`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::guess_set_from_range;
 /// 
-/// match random_guess.cmp(&my_guess) {
-///     Ordering::Equal => println!("I guessed the number!"),
-///     _ => (),
-/// }
+/// let set = guess_set_from_range(10, 20).unwrap();
+/// 
+/// assert_eq!(set.len(), 11);
+/// assert!(set.contains(&Guess::new(15).unwrap()));
+/// assert!(guess_set_from_range(0, 101).is_err());
 /// ```
-pub fn gen_random() -> Guess {
-    Guess::new(rand::thread_rng().gen_range(0..101)).unwrap()
+#[cfg(feature = "std")]
+pub fn guess_set_from_range(min: i32, max: i32) -> Result<GuessSet, err::GuessRangeError> {
+    Guess::new(min)?;
+    Guess::new(max)?;
+
+    Ok((min..=max).map(|val| Guess { val }).collect())
 }
 
 #[cfg(test)]
@@ -164,14 +858,431 @@ mod tests {
 
     #[test]
     fn parse_test() {
-        match Guess::parse("16") {
-            Ok(_) => (),
-            Err(err) => panic!("Should had no errors, got: {}", err),
+        if let Err(err) = Guess::parse("16") {
+            panic!("Should had no errors, got: {}", err);
+        }
+
+        if Guess::parse("val").is_ok() {
+            panic!("Should have panicked but didn't.");
+        }
+    }
+
+    #[test]
+    fn display_test() {
+        let guess = Guess::new(7).unwrap();
+
+        assert_eq!(guess.to_string(), "7");
+        assert_eq!(format!("{:03}", guess), "007");
+        assert_eq!(format!("{:*^5}", guess), "**7**");
+        assert_eq!(format!("{:#}", guess), "Guess(7)");
+        assert_eq!(format!("{:>10}", format!("{:#}", guess)), "  Guess(7)");
+    }
+
+    #[test]
+    fn hash_map_test() {
+        use std::collections::HashMap;
+
+        let mut hints: HashMap<Guess, &str> = HashMap::new();
+        hints.insert(Guess::new(30).unwrap(), "Too small!");
+        hints.insert(Guess::new(80).unwrap(), "Too big!");
+        hints.insert(Guess::new(30).unwrap(), "Still too small!");
+
+        assert_eq!(hints.len(), 2);
+        assert_eq!(hints.get(&Guess::new(30).unwrap()), Some(&"Still too small!"));
+        assert_eq!(hints.get(&Guess::new(80).unwrap()), Some(&"Too big!"));
+        assert_eq!(hints.get(&Guess::new(50).unwrap()), None);
+    }
+
+    #[test]
+    fn guess_set_test() {
+        let full = guess_set_from_range(0, 100).unwrap();
+        assert_eq!(full.len(), 101);
+
+        let mut set = GuessSet::new();
+        assert!(set.insert(Guess::new(5).unwrap()));
+        assert!(!set.insert(Guess::new(5).unwrap()));
+
+        assert!(guess_set_from_range(20, 10).unwrap().is_empty());
+        assert_eq!(guess_set_from_range(-1, 10), Err(err::GuessRangeError(-1)));
+    }
+
+    #[test]
+    fn copy_test() {
+        fn double(guess: Guess) -> i32 {
+            guess.get() * 2
+        }
+
+        fn is_secret(guess: Guess) -> bool {
+            guess == Guess::new(21).unwrap()
+        }
+
+        let guess = Guess::new(21).unwrap();
+
+        assert_eq!(double(guess), 42);
+        assert!(is_secret(guess));
+        assert_eq!(guess.get(), 21);
+    }
+
+    #[test]
+    fn value_test() {
+        let guess = Guess::new(21).unwrap();
+
+        assert_eq!(guess.value() + 1, 22);
+        assert_eq!(guess.value() * 2, 42);
+        assert_eq!(format!("{}", guess.value()), "21");
+        assert_eq!(guess.value(), guess.get());
+        assert_eq!(*guess.value_ref(), guess.value());
+        assert!(core::ptr::eq(guess.value_ref(), guess.as_ref()));
+    }
+
+    #[test]
+    fn clone_test() {
+        let guess = Guess::new(100).unwrap();
+        #[allow(clippy::clone_on_copy)]
+        let cloned = guess.clone();
+        let copied = guess;
+
+        assert_eq!(cloned, guess);
+        assert_eq!(copied, guess);
+        assert!(Guess::new(copied.get()).is_ok());
+    }
+
+    #[test]
+    fn constants_test() {
+        assert_eq!(Guess::MIN.get(), GUESS_RANGE_MIN);
+        assert_eq!(Guess::MAX.get(), GUESS_RANGE_MAX);
+        assert_eq!(Guess::MIDPOINT.get(), 50);
+        assert_eq!(Guess::MIN, Guess::new(0).unwrap());
+        assert_eq!(Guess::MAX, Guess::new(100).unwrap());
+        assert_eq!((crate::GUESS_RANGE_MIN, crate::GUESS_RANGE_MAX, crate::GUESS_RANGE_SIZE), (0, 100, 101));
+        assert_eq!(Guess::default(), Guess::MIN);
+        assert_eq!((GUESS_RANGE_MIN..=GUESS_RANGE_MAX).count(), GUESS_RANGE_SIZE);
+        assert!(Guess::new(GUESS_RANGE_MAX + 1).is_err());
+
+        let mut guesses = Vec::new();
+        guesses.resize_with(3, Guess::default);
+        assert_eq!(guesses, vec![Guess::MIN; 3]);
+    }
+
+    #[test]
+    fn from_str_test() {
+        use err::GuessError;
+
+        fn parse_generic<T: std::str::FromStr>(val: &str) -> Result<T, T::Err> {
+            val.parse()
+        }
+
+        assert_eq!("42".parse::<Guess>(), Ok(Guess::new(42).unwrap()));
+        assert_eq!(parse_generic::<Guess>(" 7\n"), Ok(Guess::new(7).unwrap()));
+        assert!(matches!("abc".parse::<Guess>(), Err(GuessError::ParseError(_))));
+        assert_eq!("200".parse::<Guess>(), Err(GuessError::RangeError(err::GuessRangeError(200))));
+        assert_eq!("-5".parse::<Guess>(), Err(GuessError::RangeError(err::GuessRangeError(-5))));
+    }
+
+    #[test]
+    fn new_clamped_test() {
+        assert_eq!(Guess::new_clamped(101), Guess::MAX);
+        assert_eq!(Guess::new_clamped(i32::MAX), Guess::MAX);
+        assert_eq!(Guess::new_clamped(-1), Guess::MIN);
+        assert_eq!(Guess::new_clamped(i32::MIN), Guess::MIN);
+        assert_eq!(Guess::new_clamped(0), Guess::MIN);
+        assert_eq!(Guess::new_clamped(100), Guess::MAX);
+        assert_eq!(Guess::new_clamped(57).get(), 57);
+    }
+
+    #[test]
+    fn new_clamped_from_f64_test() {
+        assert_eq!(Guess::new_clamped_from_f64(0.4).get(), 0);
+        assert_eq!(Guess::new_clamped_from_f64(99.5).get(), 100);
+        assert_eq!(Guess::new_clamped_from_f64(1e300), Guess::MAX);
+        assert_eq!(Guess::new_clamped_from_f64(-0.5), Guess::MIN);
+        assert_eq!(Guess::new_clamped_from_f64(f64::NEG_INFINITY), Guess::MIN);
+        assert_eq!(Guess::new_clamped_from_f64(f64::NAN), Guess::default());
+    }
+
+    #[test]
+    fn new_unchecked_test() {
+        for val in 0..=100 {
+            assert_eq!(unsafe { Guess::new_unchecked(val) }, Guess::new(val).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn new_unchecked_debug_assert_test() {
+        let _ = unsafe { Guess::new_unchecked(101) };
+    }
+
+    #[test]
+    fn new_const_test() {
+        const LUCKY_NUMBER: Guess = Guess::new_const(7);
+
+        assert_eq!(LUCKY_NUMBER, Guess::new(7).unwrap());
+        assert_eq!(GUESS_ZERO, Guess::MIN);
+        assert_eq!(GUESS_MAX_VAL, Guess::MAX);
+        assert_eq!(GUESS_MID, Guess::MIDPOINT);
+    }
+
+    #[test]
+    fn guess_macro_test() {
+        const SECRET: Guess = guess!(42);
+        const LOW: Guess = guess!(GUESS_RANGE_MIN);
+
+        assert_eq!(SECRET, Guess::new(42).unwrap());
+        assert_eq!(LOW, Guess::MIN);
+        assert_eq!(guess!(100), Guess::MAX);
+        assert_eq!(guess!(25 * 2), Guess::MIDPOINT);
+
+        for val in [guess!(0), guess!(50), guess!(100)] {
+            assert_eq!(Guess::new(val.get()), Ok(val));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Guess value out of range")]
+    fn new_const_runtime_panic_test() {
+        let val = std::hint::black_box(-1);
+        let _ = Guess::new_const(val);
+    }
+
+    #[test]
+    fn new_modular_test() {
+        assert_eq!(Guess::new_modular(-1).get(), 100);
+        assert_eq!(Guess::new_modular(-101).get(), 0);
+        assert_eq!(Guess::new_modular(-102).get(), 100);
+        assert_eq!(Guess::new_modular(101).get(), 0);
+        assert_eq!(Guess::new_modular(202).get(), 0);
+        assert_eq!(Guess::new_modular(101 * 5 + 3).get(), 3);
+        assert_eq!(Guess::new_modular(i32::MIN), Guess::new(i32::MIN.rem_euclid(101)).unwrap());
+
+        for val in 0..=100 {
+            assert_eq!(Guess::new_modular(val).get(), val);
+        }
+    }
+
+    #[test]
+    fn range_error_value_test() {
+        let err = Guess::new(150).unwrap_err();
+
+        assert_eq!(err.value(), 150);
+        assert_eq!(err.to_string(), "150 is outside 0-100");
+        assert_eq!(Guess::new(-1).unwrap_err(), err::GuessRangeError(-1));
+    }
+
+    #[test]
+    fn parse_error_kind_test() {
+        use err::GuessError;
+
+        assert!(matches!(Guess::parse("abc"), Err(GuessError::ParseError(_))));
+        assert!(matches!(Guess::parse("150"), Err(GuessError::RangeError(_))));
+    }
+
+    #[test]
+    fn parse_error_exhaustive_match_test() {
+        use err::GuessError;
+
+        let describe = |input: &str| match Guess::parse(input) {
+            Ok(guess) => format!("ok {}", guess),
+            Err(GuessError::ParseError(_)) => "parse".to_string(),
+            Err(GuessError::RangeError(err)) => format!("range {}", err.0),
+            Err(GuessError::InvalidDigit(_)) => "digit".to_string(),
+            Err(GuessError::DivisionByZero) => "division".to_string(),
+            Err(GuessError::InvalidFormat(_)) => "format".to_string(),
+            Err(GuessError::InsufficientValues { .. }) => "insufficient".to_string(),
+            Err(GuessError::OutOfBounds { .. }) => "bounds".to_string(),
+            Err(GuessError::InvertedRange { .. }) => "inverted".to_string(),
+        };
+
+        assert_eq!(describe(" 42 "), "ok 42");
+        assert_eq!(describe("abc"), "parse");
+        assert_eq!(describe(""), "parse");
+        assert_eq!(describe("150"), "range 150");
+        assert_eq!(describe("-1"), "range -1");
+    }
+
+    #[test]
+    fn guess_error_source_test() {
+        use std::error::Error;
+
+        let err = Guess::parse("abc").unwrap_err();
+        assert!(err.source().is_some());
+        assert!(err.to_string().starts_with("parse error: "));
+
+        let err = Guess::parse("150").unwrap_err();
+        assert!(err.source().is_some());
+        assert!(err.to_string().starts_with("range error: "));
+    }
+
+    #[test]
+    fn guess_error_source_chain_test() {
+        use err::{GuessError, GuessRangeError};
+        use std::error::Error;
+
+        for input in ["abc", "", "4.2", "99999999999"] {
+            let parse_err = input.parse::<i32>().unwrap_err();
+            let err = GuessError::ParseError(parse_err.clone());
+
+            let source = err.source().unwrap();
+            assert_eq!(source.to_string(), parse_err.to_string());
+            assert_eq!(source.downcast_ref::<std::num::ParseIntError>(), Some(&parse_err));
+            assert!(source.source().is_none());
         }
 
-        match Guess::parse("val") {
-            Ok(_) => panic!("Should have panicked but didn't."),
-            Err(_) => (),
+        let err = GuessError::RangeError(GuessRangeError(150));
+        assert_eq!(err.source().unwrap().to_string(), "150 is outside 0-100");
+        assert!(GuessRangeError(150).source().is_none());
+
+        for err in [
+            GuessError::InvalidDigit('x'),
+            GuessError::DivisionByZero,
+            GuessError::InvalidFormat("x".to_string()),
+            GuessError::InsufficientValues { requested: 102, available: 101 },
+            GuessError::OutOfBounds { value: 7, min: 1, max: 6 },
+            GuessError::InvertedRange { min: 50, max: 40 },
+        ] {
+            assert!(err.source().is_none());
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn fmt_radix_test() {
+        let guess = Guess::new(42).unwrap();
+
+        assert_eq!(format!("{:b}", guess), "101010");
+        assert_eq!(format!("{:#b}", guess), "0b101010");
+        assert_eq!(format!("{:08b}", guess), "00101010");
+        assert_eq!(format!("{:#010b}", guess), "0b00101010");
+        assert_eq!(format!("{:o}", Guess::new(8).unwrap()), "10");
+        assert_eq!(format!("{:x}", guess), "2a");
+        assert_eq!(format!("{:#x}", guess), "0x2a");
+        assert_eq!(format!("{:X}", Guess::new(16).unwrap()), "10");
+        assert_eq!(format!("{:>4X}", guess), "  2A");
+    }
+
+    #[test]
+    fn fmt_exp_test() {
+        let guess = Guess::new(42).unwrap();
+
+        assert_eq!(format!("{:e}", guess), "4.2e1");
+        assert_eq!(format!("{:E}", Guess::MAX), "1E2");
+        assert_eq!(format!("{:e}", Guess::MIN), "0e0");
+        assert_eq!(format!("{:.2e}", guess), "4.20e1");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_guess_test() {
+        let guess = Guess::new(42).unwrap();
+
+        assert_eq!(serde_json::to_value(guess).unwrap(), serde_json::json!(42));
+        assert_eq!(serde_json::from_value::<Guess>(serde_json::json!(42)).unwrap(), guess);
+
+        let json = serde_json::to_string(&guess).unwrap();
+        assert_eq!(json, "42");
+        assert_eq!(serde_json::from_str::<Guess>(&json).unwrap(), guess);
+
+        let guesses: Vec<Guess> = serde_json::from_str("[0, 50, 100]").unwrap();
+        assert_eq!(guesses, [Guess::MIN, Guess::MIDPOINT, Guess::MAX]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_guess_invalid_test() {
+        for input in ["150", "-1", "10000000000"] {
+            let err = serde_json::from_str::<Guess>(input).unwrap_err();
+
+            assert!(err.to_string().contains("outside 0-100"), "{}", err);
+        }
+        assert!(serde_json::from_str::<Guess>("\"42\"").is_err());
+        assert!(serde_json::from_str::<Guess>("4.2").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_range_error_test() {
+        let err = Guess::new(150).unwrap_err();
+        let json = serde_json::to_string(&err).unwrap();
+
+        assert_eq!(json, "150");
+        assert_eq!(serde_json::from_str::<err::GuessRangeError>(&json).unwrap(), err);
+    }
+
+    #[test]
+    fn io_error_from_range_error_test() {
+        use std::io;
+
+        let err = Guess::new(150).unwrap_err();
+        let io_err = io::Error::from(err.clone());
+
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(io_err.to_string(), err.to_string());
+        assert_eq!(io_err.into_inner().unwrap().downcast_ref::<err::GuessRangeError>(), Some(&err));
+
+        fn read_guess(val: i32) -> io::Result<Guess> {
+            Ok(Guess::new(val)?)
+        }
+
+        assert_eq!(read_guess(42).unwrap(), Guess::new(42).unwrap());
+        assert_eq!(read_guess(-1).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(read_guess(-1).unwrap_err().to_string(), "-1 is outside 0-100");
+    }
+
+    #[test]
+    fn io_error_from_guess_error_test() {
+        use std::io;
+
+        for input in ["abc", "150"] {
+            let err = Guess::parse(input).unwrap_err();
+            let io_err = io::Error::from(err.clone());
+
+            assert_eq!(io_err.kind(), io::ErrorKind::InvalidInput);
+            assert_eq!(io_err.to_string(), err.to_string());
+        }
+
+        fn read_guess(input: &str) -> io::Result<Guess> {
+            Ok(Guess::parse(input)?)
+        }
+
+        assert_eq!(read_guess("42").unwrap(), Guess::new(42).unwrap());
+        assert_eq!(read_guess("4 2").unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn new_with_fallback_test() {
+        for val in [-1000, -1, 101, 1000, i32::MIN, i32::MAX] {
+            assert_eq!(Guess::new_or_default(val), Guess::MIN);
+            assert_eq!(Guess::new_or_max(val), Guess::MAX);
+            assert_eq!(Guess::new_or_clamped(val), Guess::new_clamped(val));
+        }
+        assert_eq!(Guess::new_or_clamped(-1), Guess::MIN);
+        assert_eq!(Guess::new_or_clamped(101), Guess::MAX);
+
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+
+            assert_eq!(Guess::new_or_default(val), guess);
+            assert_eq!(Guess::new_or_max(val), guess);
+            assert_eq!(Guess::new_or_clamped(val), guess);
+        }
+    }
+
+    #[test]
+    fn scan_from_reader_test() {
+        use std::io::Cursor;
+
+        let mut input = Cursor::new(&b"42\n  7  \n100"[..]);
+        assert_eq!(Guess::scan_from_reader(&mut input).unwrap(), Guess::new(42).unwrap());
+        assert_eq!(Guess::scan_from_reader(&mut input).unwrap(), Guess::new(7).unwrap());
+        assert_eq!(Guess::scan_from_reader(&mut input).unwrap(), Guess::MAX);
+        assert!(Guess::scan_from_reader(&mut input).is_err());
+
+        let mut input = Cursor::new(&b"101\n"[..]);
+        let err = Guess::scan_from_reader(&mut input).unwrap_err();
+        assert!(matches!(err.downcast_ref::<err::GuessError>(), Some(err::GuessError::RangeError(_))));
+
+        let mut input = Cursor::new(&b"forty-two\n"[..]);
+        let err = Guess::scan_from_reader(&mut input).unwrap_err();
+        assert!(matches!(err.downcast_ref::<err::GuessError>(), Some(err::GuessError::ParseError(_))));
+    }
+}
@@ -0,0 +1,544 @@
+//! A sorted collection of unique guesses.
+
+use crate::{Guess, GuessRange};
+
+use alloc::vec::Vec;
+use core::ops::{Index, Range};
+
+/// A collection of guesses kept sorted in ascending order, without duplicates.
+/// Lookups and insertions use binary search.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessList};
+/// 
+/// let mut list = GuessList::new();
+/// 
+/// assert!(list.push(Guess::new(70).unwrap()));
+/// assert!(list.push(Guess::new(30).unwrap()));
+/// assert!(!list.push(Guess::new(70).unwrap()));
+/// 
+/// assert_eq!(list.as_slice(), [Guess::new(30).unwrap(), Guess::new(70).unwrap()]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GuessList(Vec<Guess>);
+
+impl GuessList {
+    /// Creates a new empty list.
+    pub fn new() -> GuessList {
+        GuessList(Vec::new())
+    }
+
+    /// Inserts the guess at its sorted position.
+    /// Returns `true` if the guess was not already in the list.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessList};
+    /// 
+    /// let mut list = GuessList::new();
+    /// 
+    /// assert!(list.push(Guess::MAX));
+    /// assert!(!list.push(Guess::MAX));
+    /// ```
+    pub fn push(&mut self, g: Guess) -> bool {
+        match self.0.binary_search(&g) {
+            Ok(_) => false,
+            Err(pos) => {
+                self.0.insert(pos, g);
+                true
+            }
+        }
+    }
+
+    /// Checks if the guess is in the list.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessList};
+    /// 
+    /// let mut list = GuessList::new();
+    /// list.push(Guess::MAX);
+    /// 
+    /// assert!(list.contains(&Guess::MAX));
+    /// assert!(!list.contains(&Guess::MIN));
+    /// ```
+    pub fn contains(&self, g: &Guess) -> bool {
+        self.0.binary_search(g).is_ok()
+    }
+
+    /// Removes the guess from the list.
+    /// Returns `true` if the guess was in the list.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessList};
+    /// 
+    /// let mut list = GuessList::new();
+    /// list.push(Guess::MAX);
+    /// 
+    /// assert!(list.remove(&Guess::MAX));
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn remove(&mut self, g: &Guess) -> bool {
+        match self.0.binary_search(g) {
+            Ok(pos) => {
+                self.0.remove(pos);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Counts the guesses in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks if the list holds no guesses.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets the guesses as a sorted slice.
+    pub fn as_slice(&self) -> &[Guess] {
+        &self.0
+    }
+
+    /// Gets the guess at the provided sorted position, or `None` if it is out of bounds.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessList};
+    /// 
+    /// let list: GuessList = [70, 30].into_iter().collect();
+    /// 
+    /// assert_eq!(list.get(0), Some(&Guess::new(30).unwrap()));
+    /// assert_eq!(list.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Guess> {
+        self.0.get(index)
+    }
+
+    /// Gets the smallest guess, which is always the first one.
+    pub fn min(&self) -> Option<&Guess> {
+        self.0.first()
+    }
+
+    /// Gets the largest guess, which is always the last one.
+    pub fn max(&self) -> Option<&Guess> {
+        self.0.last()
+    }
+
+    /// Gets the range from the smallest to the largest guess.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{GuessList, GuessRange};
+    /// 
+    /// let list: GuessList = [70, 30, 50].into_iter().collect();
+    /// 
+    /// assert_eq!(list.range_span(), GuessRange::new(30, 70).ok());
+    /// assert_eq!(GuessList::new().range_span(), None);
+    /// ```
+    pub fn range_span(&self) -> Option<GuessRange> {
+        Some(GuessRange::from_ordered_pair(self.min()?, self.max()?))
+    }
+
+    /// Calculates the median of the guesses,
+    /// the mean of the two middle ones when the length is even.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessList;
+    /// 
+    /// let list: GuessList = [10, 20, 30, 45].into_iter().collect();
+    /// 
+    /// assert_eq!(list.median(), Some(25.0));
+    /// ```
+    pub fn median(&self) -> Option<f64> {
+        let mid = self.len() / 2;
+
+        match self.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(self.0[mid].val as f64),
+            _ => Some((self.0[mid - 1].val + self.0[mid].val) as f64 / 2.0),
+        }
+    }
+
+    /// Creates a new list holding the guesses present in both lists.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessList};
+    /// 
+    /// let mut a = GuessList::new();
+    /// a.push(Guess::MIN);
+    /// a.push(Guess::MAX);
+    /// 
+    /// let mut b = GuessList::new();
+    /// b.push(Guess::MAX);
+    /// 
+    /// assert_eq!(a.intersection(&b).as_slice(), [Guess::MAX]);
+    /// ```
+    pub fn intersection(&self, other: &GuessList) -> GuessList {
+        GuessList(self.0.iter().copied().filter(|g| other.contains(g)).collect())
+    }
+
+    /// Creates a new list holding the guesses present in either list.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessList};
+    /// 
+    /// let mut a = GuessList::new();
+    /// a.push(Guess::MAX);
+    /// 
+    /// let mut b = GuessList::new();
+    /// b.push(Guess::MIN);
+    /// 
+    /// assert_eq!(a.union(&b).as_slice(), [Guess::MIN, Guess::MAX]);
+    /// ```
+    pub fn union(&self, other: &GuessList) -> GuessList {
+        let mut merged = Vec::with_capacity(self.len() + other.len());
+        let (mut a, mut b) = (self.0.iter().peekable(), other.0.iter().peekable());
+
+        loop {
+            let next = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) if x < y => a.next(),
+                (Some(x), Some(y)) if x > y => b.next(),
+                (Some(_), Some(_)) => {
+                    b.next();
+                    a.next()
+                }
+                (Some(_), None) => a.next(),
+                (None, Some(_)) => b.next(),
+                (None, None) => break,
+            };
+
+            merged.extend(next.copied());
+        }
+
+        GuessList(merged)
+    }
+
+    /// Creates a new list holding the guesses present in this list but not in `other`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessList};
+    /// 
+    /// let a: GuessList = (0i32..5).collect();
+    /// let b: GuessList = (2i32..10).collect();
+    /// 
+    /// assert_eq!(a.difference(&b), (0i32..2).collect());
+    /// ```
+    pub fn difference(&self, other: &GuessList) -> GuessList {
+        GuessList(self.0.iter().copied().filter(|g| !other.contains(g)).collect())
+    }
+
+    /// Keeps only the guesses for which `f` returns `true`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessList;
+    /// 
+    /// let mut list: GuessList = (0i32..10).collect();
+    /// list.retain(|g| g.is_even());
+    /// 
+    /// assert_eq!(list.len(), 5);
+    /// ```
+    pub fn retain(&mut self, f: impl Fn(&Guess) -> bool) {
+        self.0.retain(f);
+    }
+}
+
+impl IntoIterator for GuessList {
+    type Item = Guess;
+    type IntoIter = alloc::vec::IntoIter<Guess>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GuessList {
+    type Item = &'a Guess;
+    type IntoIter = core::slice::Iter<'a, Guess>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// Allows indexing the list by sorted position, so `list[0]` is the smallest guess.
+/// Panics if the index is out of bounds.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessList};
+/// 
+/// let list: GuessList = [70, 30, 50].into_iter().collect();
+/// 
+/// assert_eq!(list[0], Guess::new(30).unwrap());
+/// assert_eq!(list[list.len() - 1], Guess::new(70).unwrap());
+/// ```
+impl Index<usize> for GuessList {
+    type Output = Guess;
+
+    fn index(&self, index: usize) -> &Guess {
+        &self.0[index]
+    }
+}
+
+/// Allows slicing the list by sorted positions.
+/// Panics if the range is out of bounds.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessList};
+/// 
+/// let list: GuessList = [70, 30, 50].into_iter().collect();
+/// 
+/// assert_eq!(list[1..3], [Guess::new(50).unwrap(), Guess::new(70).unwrap()]);
+/// ```
+impl Index<Range<usize>> for GuessList {
+    type Output = [Guess];
+
+    fn index(&self, index: Range<usize>) -> &[Guess] {
+        &self.0[index]
+    }
+}
+
+/// Allows collecting guesses into a sorted list, merging duplicates.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessList};
+/// 
+/// let list: GuessList = [Guess::MAX, Guess::MIN, Guess::MAX].into_iter().collect();
+/// 
+/// assert_eq!(list.as_slice(), [Guess::MIN, Guess::MAX]);
+/// ```
+impl FromIterator<Guess> for GuessList {
+    fn from_iter<I: IntoIterator<Item = Guess>>(iter: I) -> Self {
+        let mut guesses: Vec<Guess> = iter.into_iter().collect();
+        guesses.sort_unstable();
+        guesses.dedup();
+
+        GuessList(guesses)
+    }
+}
+
+/// Allows collecting integers into a sorted list, silently skipping values outside (0..101) range.
+/// # Example
+/// ```
+/// use guessing_utils::GuessList;
+/// 
+/// assert_eq!((0i32..=100).collect::<GuessList>().len(), 101);
+/// assert_eq!((-50i32..=150).collect::<GuessList>().len(), 101);
+/// ```
+impl FromIterator<i32> for GuessList {
+    fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+        iter.into_iter().filter_map(|val| Guess::new(val).ok()).collect()
+    }
+}
+
+/// Allows adding many guesses at once, merging duplicates.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessList};
+/// 
+/// let mut list = GuessList::new();
+/// list.extend(vec![Guess::MAX, Guess::MIN]);
+/// 
+/// assert_eq!(list.as_slice(), [Guess::MIN, Guess::MAX]);
+/// ```
+impl Extend<Guess> for GuessList {
+    fn extend<I: IntoIterator<Item = Guess>>(&mut self, iter: I) {
+        self.0.extend(iter);
+        self.0.sort_unstable();
+        self.0.dedup();
+    }
+}
+
+/// Allows adding many integers at once, silently skipping values outside (0..101) range.
+/// # Example
+/// ```
+/// use guessing_utils::GuessList;
+/// 
+/// let mut list = GuessList::new();
+/// list.extend(90..=110);
+/// 
+/// assert_eq!(list.len(), 11);
+/// ```
+impl Extend<i32> for GuessList {
+    fn extend<I: IntoIterator<Item = i32>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().filter_map(|val| Guess::new(val).ok()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(values: &[i32]) -> GuessList {
+        let mut list = GuessList::new();
+        for &val in values {
+            list.push(Guess::new(val).unwrap());
+        }
+        list
+    }
+
+    fn values(list: &GuessList) -> Vec<i32> {
+        list.as_slice().iter().map(|g| g.get()).collect()
+    }
+
+    #[test]
+    fn push_test() {
+        let mut list = GuessList::new();
+
+        assert!(list.is_empty());
+        for (val, inserted) in [(50, true), (10, true), (90, true), (50, false), (0, true), (10, false), (100, true)] {
+            assert_eq!(list.push(Guess::new(val).unwrap()), inserted);
+            assert!(list.as_slice().windows(2).all(|w| w[0] < w[1]));
+        }
+
+        assert_eq!(values(&list), [0, 10, 50, 90, 100]);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn contains_remove_test() {
+        let mut list = list(&[5, 15, 25]);
+
+        assert!(list.contains(&Guess::new(15).unwrap()));
+        assert!(!list.contains(&Guess::new(20).unwrap()));
+        assert!(list.remove(&Guess::new(15).unwrap()));
+        assert!(!list.remove(&Guess::new(15).unwrap()));
+        assert!(!list.contains(&Guess::new(15).unwrap()));
+        assert_eq!(values(&list), [5, 25]);
+    }
+
+    #[test]
+    fn set_operations_test() {
+        let a = list(&[1, 3, 5, 7, 9]);
+        let b = list(&[3, 4, 5, 6]);
+
+        assert_eq!(values(&a.intersection(&b)), [3, 5]);
+        assert_eq!(values(&a.union(&b)), [1, 3, 4, 5, 6, 7, 9]);
+        assert_eq!(a.union(&b), b.union(&a));
+        assert_eq!(a.intersection(&GuessList::new()), GuessList::new());
+        assert_eq!(a.union(&GuessList::new()), a);
+    }
+
+    #[test]
+    fn from_iter_test() {
+        assert_eq!((0i32..=100).collect::<GuessList>().len(), 101);
+        assert_eq!(values(&[9, 3, 9, 1, 3].into_iter().collect()), [1, 3, 9]);
+        assert_eq!(values(&[-1, 50, 101, 200, 0].into_iter().collect()), [0, 50]);
+        assert!(std::iter::empty::<i32>().collect::<GuessList>().is_empty());
+
+        let guesses: GuessList = [Guess::MAX, Guess::MIDPOINT, Guess::MAX].into_iter().collect();
+        assert_eq!(guesses, list(&[50, 100]));
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let list = list(&[30, 10, 20]);
+
+        let mut borrowed = Vec::new();
+        for g in &list {
+            borrowed.push(g.get());
+        }
+        assert_eq!(borrowed, [10, 20, 30]);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Guess::new(10).unwrap()));
+        assert_eq!(iter.next(), Some(Guess::new(20).unwrap()));
+        assert_eq!(iter.next(), Some(Guess::new(30).unwrap()));
+        assert_eq!(iter.next(), None);
+    }
+
+    fn is_sorted_unique(list: &GuessList) -> bool {
+        list.as_slice().windows(2).all(|w| w[0] < w[1])
+    }
+
+    #[test]
+    fn extend_test() {
+        let mut empty = GuessList::new();
+        empty.extend(vec![Guess::MAX, Guess::MIN, Guess::MAX]);
+        assert_eq!(values(&empty), [0, 100]);
+
+        let mut list = list(&[10, 20, 30]);
+        list.extend(self::list(&[5, 20, 25, 40]));
+        assert_eq!(values(&list), [5, 10, 20, 25, 30, 40]);
+        assert!(is_sorted_unique(&list));
+
+        list.extend(95..=105);
+        assert_eq!(list.len(), 12);
+        assert!(is_sorted_unique(&list));
+
+        list.extend(-10..0);
+        assert_eq!(list.len(), 12);
+
+        list.extend(0..=50i32);
+        assert_eq!(list.len(), 51 + 6);
+        assert!(is_sorted_unique(&list));
+    }
+
+    #[test]
+    fn retain_difference_test() {
+        let mut list: GuessList = (0i32..=20).collect();
+
+        list.retain(|g| g.get() % 5 == 0);
+        assert_eq!(values(&list), [0, 5, 10, 15, 20]);
+        assert!(is_sorted_unique(&list));
+
+        let other = self::list(&[5, 15, 99]);
+        assert_eq!(values(&list.difference(&other)), [0, 10, 20]);
+        assert_eq!(values(&other.difference(&list)), [99]);
+        assert!(list.difference(&list).is_empty());
+    }
+
+    #[test]
+    fn index_test() {
+        let list = list(&[50, 10, 90, 30]);
+
+        assert_eq!(list[0], Guess::new(10).unwrap());
+        assert_eq!(list[list.len() - 1], Guess::new(90).unwrap());
+        assert_eq!(list[1..3], [Guess::new(30).unwrap(), Guess::new(50).unwrap()]);
+        assert_eq!(list[0..list.len()], *list.as_slice());
+        assert!(list[2..2].is_empty());
+
+        for i in 0..list.len() {
+            assert_eq!(list.get(i), Some(&list[i]));
+        }
+        assert_eq!(list.get(list.len()), None);
+        assert_eq!(GuessList::new().get(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_empty_test() {
+        let _ = GuessList::new()[0];
+    }
+
+    #[test]
+    fn summary_test() {
+        let empty = GuessList::new();
+        assert_eq!((empty.min(), empty.max(), empty.range_span(), empty.median()), (None, None, None, None));
+
+        let single = list(&[42]);
+        assert_eq!(single.min(), Some(&Guess::new(42).unwrap()));
+        assert_eq!(single.max(), single.min());
+        assert_eq!(single.range_span(), GuessRange::new(42, 42).ok());
+        assert_eq!(single.median(), Some(42.0));
+
+        let even = list(&[90, 10, 40, 25]);
+        assert_eq!(even.min(), Some(&Guess::new(10).unwrap()));
+        assert_eq!(even.max(), Some(&Guess::new(90).unwrap()));
+        assert_eq!(even.range_span(), GuessRange::new(10, 90).ok());
+        assert_eq!(even.median(), Some(32.5));
+        assert_eq!(list(&[10, 20]).median(), Some(15.0));
+
+        let odd = list(&[100, 0, 7, 50, 3]);
+        assert_eq!(odd.min(), Some(&Guess::MIN));
+        assert_eq!(odd.max(), Some(&Guess::MAX));
+        assert_eq!(odd.range_span(), Some(GuessRange::from_full_range()));
+        assert_eq!(odd.median(), Some(7.0));
+
+        // `min` and `max` read the ends of the sorted vec, without searching through it.
+        for l in [&single, &even, &odd] {
+            assert!(core::ptr::eq(l.min().unwrap(), &l.as_slice()[0]));
+            assert!(core::ptr::eq(l.max().unwrap(), &l.as_slice()[l.len() - 1]));
+        }
+    }
+}
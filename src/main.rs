@@ -1,4 +1,3 @@
-use guessing_utils;
 use guessing_utils::Guess;
 
 use std::io;
@@ -24,7 +23,7 @@ fn main() {
             Err(_) => continue,
         };
 
-        println!("You guessed: {}", guess.value());
+        println!("You guessed: {}", guess);
 
         match guess.cmp(&secret_number) {
             Ordering::Less => println!("Too small!"),
@@ -0,0 +1,398 @@
+//! Number-theoretic predicates for guesses.
+//! 
+//! # Example
+//! ```
+//! use guessing_utils::Guess;
+//! use guessing_utils::math;
+//! 
+//! let guess = Guess::new(64).unwrap();
+//! 
+//! assert!(math::is_perfect_square(&guess) && math::is_perfect_cube(&guess));
+//! assert!(math::is_divisible_by(&guess, 16));
+//! ```
+
+use crate::{Guess, GUESS_RANGE_SIZE};
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// Every perfect square in (0..101) range.
+pub const PERFECT_SQUARES: [i32; 11] = [0, 1, 4, 9, 16, 25, 36, 49, 64, 81, 100];
+
+/// Every perfect cube in (0..101) range.
+pub const PERFECT_CUBES: [i32; 5] = [0, 1, 8, 27, 64];
+
+/// Every prime number in (0..101) range.
+pub const PRIMES_IN_RANGE: [i32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Lookup table of `PRIMES_IN_RANGE`, indexed by value.
+const PRIME_TABLE: [bool; GUESS_RANGE_SIZE] = {
+    let mut table = [false; GUESS_RANGE_SIZE];
+    let mut i = 0;
+
+    while i < PRIMES_IN_RANGE.len() {
+        table[PRIMES_IN_RANGE[i] as usize] = true;
+        i += 1;
+    }
+
+    table
+};
+
+/// Checks if the guess is an even number. Zero is even.
+pub fn is_even(g: &Guess) -> bool {
+    g.val % 2 == 0
+}
+
+/// Checks if the guess is an odd number.
+pub fn is_odd(g: &Guess) -> bool {
+    !is_even(g)
+}
+
+/// Checks if the guess is divisible by `d` without a remainder.
+/// Nothing is divisible by zero, so `d = 0` always returns `false`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::math;
+/// 
+/// assert!(math::is_divisible_by(&Guess::new(42).unwrap(), 7));
+/// assert!(!math::is_divisible_by(&Guess::new(42).unwrap(), 0));
+/// ```
+pub fn is_divisible_by(g: &Guess, d: u32) -> bool {
+    d != 0 && (g.val as u32).is_multiple_of(d)
+}
+
+/// Checks if the guess is a perfect square, see `PERFECT_SQUARES`.
+pub fn is_perfect_square(g: &Guess) -> bool {
+    PERFECT_SQUARES.contains(&g.val)
+}
+
+/// Checks if the guess is a perfect cube, see `PERFECT_CUBES`.
+pub fn is_perfect_cube(g: &Guess) -> bool {
+    PERFECT_CUBES.contains(&g.val)
+}
+
+/// Checks if the guess is a prime number, see `PRIMES_IN_RANGE`.
+/// Neither `0` nor `1` is prime.
+pub fn is_prime(g: &Guess) -> bool {
+    PRIME_TABLE[g.val as usize]
+}
+
+/// Checks if the guess is a composite number, a number bigger than `1` which is not prime.
+pub fn is_composite(g: &Guess) -> bool {
+    g.val > 1 && !is_prime(g)
+}
+
+impl Guess {
+    /// Checks if the guess is an even number. Zero is even.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert!(Guess::new(42).unwrap().is_even());
+    /// ```
+    pub fn is_even(&self) -> bool {
+        is_even(self)
+    }
+
+    /// Checks if the guess is an odd number.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert!(Guess::new(43).unwrap().is_odd());
+    /// ```
+    pub fn is_odd(&self) -> bool {
+        is_odd(self)
+    }
+
+    /// Checks if the guess is a prime number. Neither `0` nor `1` is prime.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert!(Guess::new(97).unwrap().is_prime());
+    /// assert!(!Guess::new(1).unwrap().is_prime());
+    /// ```
+    pub fn is_prime(&self) -> bool {
+        is_prime(self)
+    }
+
+    /// Checks if the guess is a composite number, a number bigger than `1` which is not prime.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert!(Guess::new(91).unwrap().is_composite());
+    /// assert!(!Guess::new(0).unwrap().is_composite());
+    /// ```
+    pub fn is_composite(&self) -> bool {
+        is_composite(self)
+    }
+
+    /// Gets the smallest prime strictly bigger than the guess,
+    /// or `None` when there is no such prime in the range (for values from `97`).
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(90).unwrap().next_prime(), Some(Guess::new(97).unwrap()));
+    /// assert_eq!(Guess::new(97).unwrap().next_prime(), None);
+    /// ```
+    pub fn next_prime(&self) -> Option<Guess> {
+        PRIMES_IN_RANGE.iter().find(|&&p| p > self.val).map(|&val| Guess { val })
+    }
+
+    /// Gets the biggest prime strictly smaller than the guess,
+    /// or `None` when there is no such prime (for values below `3`).
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(98).unwrap().prev_prime(), Some(Guess::new(97).unwrap()));
+    /// assert_eq!(Guess::new(2).unwrap().prev_prime(), None);
+    /// ```
+    pub fn prev_prime(&self) -> Option<Guess> {
+        PRIMES_IN_RANGE.iter().rev().find(|&&p| p < self.val).map(|&val| Guess { val })
+    }
+
+    /// Gets all positive divisors of the guess in ascending order.
+    /// Divisors of zero are undefined, so an empty `Vec` is returned for `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(12).unwrap().factors(), vec![1, 2, 3, 4, 6, 12]);
+    /// assert!(Guess::MIN.factors().is_empty());
+    /// ```
+    pub fn factors(&self) -> Vec<u32> {
+        let val = self.val as u32;
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+
+        let mut d = 1;
+        while d * d <= val {
+            if val.is_multiple_of(d) {
+                small.push(d);
+                if d * d != val {
+                    large.push(val / d);
+                }
+            }
+            d += 1;
+        }
+
+        small.extend(large.into_iter().rev());
+        small
+    }
+
+    /// Counts the positive divisors of the guess, `0` for a guess of `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(37).unwrap().factor_count(), 2);
+    /// ```
+    pub fn factor_count(&self) -> usize {
+        self.factors().len()
+    }
+
+    /// Gets the decimal digits of the guess, most significant first.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().decimal_digits(), vec![4, 2]);
+    /// assert_eq!(Guess::MAX.decimal_digits(), vec![1, 0, 0]);
+    /// ```
+    pub fn decimal_digits(&self) -> Vec<u8> {
+        self.val.to_string().bytes().map(|digit| digit - b'0').collect()
+    }
+
+    /// Sums the decimal digits of the guess.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().digit_sum(), 6);
+    /// ```
+    pub fn digit_sum(&self) -> u32 {
+        self.decimal_digits().iter().map(|&digit| digit as u32).sum()
+    }
+
+    /// Multiplies the decimal digits of the guess.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().digit_product(), 8);
+    /// ```
+    pub fn digit_product(&self) -> u32 {
+        self.decimal_digits().iter().map(|&digit| digit as u32).product()
+    }
+
+    /// Counts the decimal digits of the guess.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(7).unwrap().digit_count(), 1);
+    /// assert_eq!(Guess::MAX.digit_count(), 3);
+    /// ```
+    pub fn digit_count(&self) -> usize {
+        self.decimal_digits().len()
+    }
+
+    /// Reverses the decimal digits of the guess, dropping leading zeros of the result.
+    /// Returns `None` if the reversed value is outside (0..101) range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(59).unwrap().reverse_digits(), Some(Guess::new(95).unwrap()));
+    /// assert_eq!(Guess::MAX.reverse_digits(), Some(Guess::new(1).unwrap()));
+    /// ```
+    pub fn reverse_digits(&self) -> Option<Guess> {
+        let reversed = self.decimal_digits().iter().rev().fold(0, |acc, &digit| acc * 10 + digit as i32);
+
+        Guess::new(reversed).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_guesses() -> impl Iterator<Item = Guess> {
+        (0..=100).map(|val| Guess::new(val).unwrap())
+    }
+
+    #[test]
+    fn parity_test() {
+        for guess in all_guesses() {
+            assert_eq!(guess.is_even(), guess.get() % 2 == 0);
+            assert_ne!(guess.is_even(), guess.is_odd());
+            assert_eq!(is_even(&guess), is_divisible_by(&guess, 2));
+        }
+    }
+
+    #[test]
+    fn divisible_by_test() {
+        for guess in all_guesses() {
+            assert!(is_divisible_by(&guess, 1));
+            assert!(!is_divisible_by(&guess, 0));
+            assert_eq!(is_divisible_by(&guess, 3), (0..=33).any(|k| k * 3 == guess.get()));
+        }
+    }
+
+    #[test]
+    fn perfect_powers_test() {
+        for guess in all_guesses() {
+            let val = guess.get();
+
+            assert_eq!(is_perfect_square(&guess), (0..=10).any(|n| n * n == val));
+            assert_eq!(is_perfect_cube(&guess), (0..=4).any(|n| n * n * n == val));
+        }
+
+        assert_eq!(all_guesses().filter(is_perfect_square).count(), PERFECT_SQUARES.len());
+        assert_eq!(all_guesses().filter(is_perfect_cube).count(), PERFECT_CUBES.len());
+    }
+
+    #[test]
+    fn is_prime_test() {
+        for guess in all_guesses() {
+            let val = guess.get();
+            let reference = val > 1 && (2..val).all(|d| val % d != 0);
+
+            assert_eq!(guess.is_prime(), reference, "{}", val);
+        }
+
+        assert_eq!(all_guesses().filter(Guess::is_prime).count(), PRIMES_IN_RANGE.len());
+    }
+
+    #[test]
+    fn prime_composite_partition_test() {
+        for guess in all_guesses() {
+            if guess.get() < 2 {
+                assert!(!guess.is_prime() && !guess.is_composite());
+            } else {
+                assert_ne!(guess.is_prime(), guess.is_composite());
+            }
+        }
+    }
+
+    #[test]
+    fn next_prev_prime_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(guess(97).next_prime(), None);
+        assert_eq!(guess(100).next_prime(), None);
+        assert_eq!(guess(96).next_prime(), Some(guess(97)));
+        assert_eq!(guess(0).next_prime(), Some(guess(2)));
+        assert_eq!(guess(2).next_prime(), Some(guess(3)));
+
+        assert_eq!(guess(2).prev_prime(), None);
+        assert_eq!(guess(0).prev_prime(), None);
+        assert_eq!(guess(3).prev_prime(), Some(guess(2)));
+        assert_eq!(guess(98).prev_prime(), Some(guess(97)));
+
+        for g in all_guesses() {
+            if let Some(next) = g.next_prime() {
+                assert!(next.is_prime() && next > g);
+                assert!((g.get() + 1..next.get()).all(|val| !guess(val).is_prime()));
+            }
+        }
+    }
+
+    #[test]
+    fn factors_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(guess(12).factors(), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(guess(37).factors(), vec![1, 37]);
+        assert_eq!(guess(100).factors(), vec![1, 2, 4, 5, 10, 20, 25, 50, 100]);
+        assert_eq!(guess(1).factors(), vec![1]);
+        assert_eq!(guess(0).factors(), Vec::<u32>::new());
+
+        for g in all_guesses() {
+            let reference: Vec<u32> = (1..=g.get() as u32).filter(|&d| (g.get() as u32).is_multiple_of(d)).collect();
+
+            assert_eq!(g.factors(), reference);
+            assert_eq!(g.factor_count(), g.factors().len());
+            assert_eq!(g.factor_count() == 2, g.is_prime());
+        }
+    }
+
+    #[test]
+    fn decimal_digits_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(guess(0).decimal_digits(), vec![0]);
+        assert_eq!(guess(7).decimal_digits(), vec![7]);
+        assert_eq!(guess(42).decimal_digits(), vec![4, 2]);
+        assert_eq!(guess(100).decimal_digits(), vec![1, 0, 0]);
+
+        assert_eq!(guess(42).digit_sum(), 6);
+        assert_eq!(guess(99).digit_sum(), 18);
+        assert_eq!(guess(42).digit_product(), 8);
+        assert_eq!(guess(70).digit_product(), 0);
+        assert_eq!(guess(100).digit_count(), 3);
+        assert_eq!(guess(0).digit_count(), 1);
+    }
+
+    #[test]
+    fn reverse_digits_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(guess(42).reverse_digits(), Some(guess(24)));
+        assert_eq!(guess(59).reverse_digits(), Some(guess(95)));
+        assert_eq!(guess(70).reverse_digits(), Some(guess(7)));
+        assert_eq!(guess(100).reverse_digits(), Some(guess(1)));
+        assert_eq!(guess(0).reverse_digits(), Some(guess(0)));
+
+        for g in all_guesses().filter(|g| g.get() % 10 != 0) {
+            assert_eq!(g.reverse_digits().and_then(|r| r.reverse_digits()), Some(g));
+        }
+    }
+}
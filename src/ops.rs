@@ -0,0 +1,675 @@
+//! Arithmetic on guesses.
+
+use crate::err::{GuessError, GuessRangeError};
+use crate::{Guess, GUESS_RANGE_SIZE};
+
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Sub};
+
+impl Guess {
+    /// Adds the provided number to the guess, clamping the result into (0..101) range.
+    /// Mirrors `i32::saturating_add`, the value simply stops changing at the boundaries.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(95).unwrap();
+    /// 
+    /// assert_eq!(guess.saturating_add(3).get(), 98);
+    /// assert_eq!(guess.saturating_add(10), Guess::MAX);
+    /// ```
+    pub fn saturating_add(&self, rhs: i32) -> Guess {
+        Guess::new_clamped(self.val.saturating_add(rhs))
+    }
+
+    /// Subtracts the provided number from the guess, clamping the result into (0..101) range.
+    /// Mirrors `i32::saturating_sub`, the value simply stops changing at the boundaries.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(5).unwrap();
+    /// 
+    /// assert_eq!(guess.saturating_sub(3).get(), 2);
+    /// assert_eq!(guess.saturating_sub(10), Guess::MIN);
+    /// ```
+    pub fn saturating_sub(&self, rhs: i32) -> Guess {
+        Guess::new_clamped(self.val.saturating_sub(rhs))
+    }
+
+    /// Adds the provided number to the guess,
+    /// returning `None` when the result is outside (0..101) range.
+    /// Mirrors `i32::checked_add` and never panics, whatever the provided number is.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(99).unwrap();
+    /// 
+    /// assert_eq!(guess.checked_add(1), Some(Guess::MAX));
+    /// assert_eq!(guess.checked_add(2), None);
+    /// ```
+    pub fn checked_add(&self, rhs: i32) -> Option<Guess> {
+        self.val.checked_add(rhs).and_then(|val| Guess::new(val).ok())
+    }
+
+    /// Subtracts the provided number from the guess,
+    /// returning `None` when the result is outside (0..101) range.
+    /// Mirrors `i32::checked_sub` and never panics, whatever the provided number is.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(1).unwrap();
+    /// 
+    /// assert_eq!(guess.checked_sub(1), Some(Guess::MIN));
+    /// assert_eq!(guess.checked_sub(2), None);
+    /// ```
+    pub fn checked_sub(&self, rhs: i32) -> Option<Guess> {
+        self.val.checked_sub(rhs).and_then(|val| Guess::new(val).ok())
+    }
+
+    /// Adds the provided number to the guess, wrapping around (0..101) range.
+    /// The range is treated as a ring of 101 elements, so `100` plus `1` gives `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::MAX.wrapping_add(1), Guess::MIN);
+    /// assert_eq!(Guess::new(10).unwrap().wrapping_add(202).get(), 10);
+    /// ```
+    pub fn wrapping_add(&self, rhs: i32) -> Guess {
+        // reducing first keeps the sum far from overflowing
+        Guess::new_modular(self.val + rhs.rem_euclid(GUESS_RANGE_SIZE as i32))
+    }
+
+    /// Subtracts the provided number from the guess, wrapping around (0..101) range.
+    /// The range is treated as a ring of 101 elements, so `0` minus `1` gives `100`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::MIN.wrapping_sub(1), Guess::MAX);
+    /// ```
+    pub fn wrapping_sub(&self, rhs: i32) -> Guess {
+        Guess::new_modular(self.val - rhs.rem_euclid(GUESS_RANGE_SIZE as i32))
+    }
+
+    /// Adds the provided guess to this one, wrapping around (0..101) range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(60).unwrap();
+    /// 
+    /// assert_eq!(guess.wrapping_add_guess(&guess).get(), 19);
+    /// ```
+    pub fn wrapping_add_guess(&self, rhs: &Guess) -> Guess {
+        self.wrapping_add(rhs.val)
+    }
+
+    /// Multiplies the guess by the provided number, clamping the result into (0..101) range.
+    /// Negative multipliers produce negative products, which are clamped to `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(30).unwrap();
+    /// 
+    /// assert_eq!(guess.saturating_mul(3).get(), 90);
+    /// assert_eq!(guess.saturating_mul(4), Guess::MAX);
+    /// assert_eq!(guess.saturating_mul(-1), Guess::MIN);
+    /// ```
+    pub fn saturating_mul(&self, rhs: i32) -> Guess {
+        Guess::new_clamped(self.val.saturating_mul(rhs))
+    }
+
+    /// Multiplies the guess by the provided number,
+    /// returning `None` when the result is outside (0..101) range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(3).unwrap();
+    /// 
+    /// assert_eq!(guess.checked_mul(33), Some(Guess::new(99).unwrap()));
+    /// assert_eq!(guess.checked_mul(34), None);
+    /// ```
+    pub fn checked_mul(&self, rhs: i32) -> Option<Guess> {
+        self.val.checked_mul(rhs).and_then(|val| Guess::new(val).ok())
+    }
+
+    /// Divides the guess by the provided number,
+    /// returning `None` when dividing by zero or when the result is outside (0..101) range.
+    /// Only negative divisors can produce out-of-range results.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(50).unwrap();
+    /// 
+    /// assert_eq!(guess.checked_div(2), Some(Guess::new(25).unwrap()));
+    /// assert_eq!(guess.checked_div(0), None);
+    /// assert_eq!(guess.checked_div(-2), None);
+    /// ```
+    pub fn checked_div(&self, rhs: i32) -> Option<Guess> {
+        self.val.checked_div(rhs).and_then(|val| Guess::new(val).ok())
+    }
+
+    /// Divides the guess by the provided number, clamping the result into (0..101) range.
+    /// # Panics
+    /// Panics when `rhs` is zero, just like `i32::saturating_div`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(50).unwrap();
+    /// 
+    /// assert_eq!(guess.saturating_div(2).get(), 25);
+    /// assert_eq!(guess.saturating_div(-2), Guess::MIN);
+    /// ```
+    pub fn saturating_div(&self, rhs: i32) -> Guess {
+        Guess::new_clamped(self.val / rhs)
+    }
+
+    /// Calculates the remainder of dividing the guess by the provided number,
+    /// returning `None` when dividing by zero.
+    /// The remainder of a guess is always a valid guess itself.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(37).unwrap();
+    /// 
+    /// assert_eq!(guess.checked_rem(10), Some(Guess::new(7).unwrap()));
+    /// assert_eq!(guess.checked_rem(0), None);
+    /// ```
+    pub fn checked_rem(&self, rhs: i32) -> Option<Guess> {
+        self.val.checked_rem(rhs).map(|val| Guess { val })
+    }
+
+    /// Calculates the absolute difference between two guesses,
+    /// which measures how close they are to each other. Mirrors `i32::abs_diff`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let guess = Guess::new(50).unwrap();
+    /// 
+    /// assert_eq!(guess.abs_diff(&secret), 8);
+    /// assert_eq!(secret.abs_diff(&guess), 8);
+    /// ```
+    pub fn abs_diff(&self, other: &Guess) -> u32 {
+        self.val.abs_diff(other.val)
+    }
+
+    /// Calculates the difference between two guesses, preserving the direction:
+    /// the result is positive when this guess is the bigger one.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let guess = Guess::new(50).unwrap();
+    /// 
+    /// assert_eq!(guess.signed_diff(&secret), 8);
+    /// assert_eq!(secret.signed_diff(&guess), -8);
+    /// ```
+    pub fn signed_diff(&self, other: &Guess) -> i32 {
+        self.val - other.val
+    }
+}
+
+/// Adds a number to the guess, failing if the result is outside (0..101) range.
+/// Results which overflow an `i32` are reported as saturated values in the error.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::err::GuessRangeError;
+/// 
+/// fn step(guess: Guess) -> Result<Guess, GuessRangeError> {
+///     let guess = (guess + 10)?;
+///     guess + 10
+/// }
+/// 
+/// assert_eq!(step(Guess::new(50).unwrap()), Ok(Guess::new(70).unwrap()));
+/// assert!(step(Guess::new(90).unwrap()).is_err());
+/// ```
+impl Add<i32> for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn add(self, rhs: i32) -> Self::Output {
+        Guess::new(self.val.saturating_add(rhs))
+    }
+}
+
+impl Add<i32> for &Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn add(self, rhs: i32) -> Self::Output {
+        *self + rhs
+    }
+}
+
+impl Add<Guess> for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn add(self, rhs: Guess) -> Self::Output {
+        self + rhs.val
+    }
+}
+
+/// Subtracts a number from the guess, failing if the result is outside (0..101) range.
+/// Results which overflow an `i32` are reported as saturated values in the error.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// let guess = Guess::new(50).unwrap();
+/// 
+/// assert_eq!(guess - 10, Ok(Guess::new(40).unwrap()));
+/// assert!((guess - 60).is_err());
+/// ```
+impl Sub<i32> for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn sub(self, rhs: i32) -> Self::Output {
+        Guess::new(self.val.saturating_sub(rhs))
+    }
+}
+
+impl Sub<i32> for &Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn sub(self, rhs: i32) -> Self::Output {
+        *self - rhs
+    }
+}
+
+/// Subtracts one guess from another. The difference is a valid guess itself
+/// unless the right-hand side is the bigger one, in which case an error is returned.
+impl Sub<Guess> for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn sub(self, rhs: Guess) -> Self::Output {
+        self - rhs.val
+    }
+}
+
+/// Multiplies the guess by a number, failing if the result is outside (0..101) range.
+/// Results which overflow an `i32` are reported as saturated values in the error.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert_eq!(Guess::new(3).unwrap() * 33, Ok(Guess::new(99).unwrap()));
+/// assert!((Guess::new(4).unwrap() * 26).is_err());
+/// ```
+impl Mul<i32> for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Guess::new(self.val.saturating_mul(rhs))
+    }
+}
+
+/// Divides the guess by a number. Dividing by zero returns `GuessError::DivisionByZero`,
+/// while negative divisors of non-zero guesses return `GuessError::RangeError`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::err::GuessError;
+/// 
+/// let guess = Guess::new(50).unwrap();
+/// 
+/// assert_eq!(guess / 2, Ok(Guess::new(25).unwrap()));
+/// assert_eq!(guess / 0, Err(GuessError::DivisionByZero));
+/// ```
+impl Div<i32> for Guess {
+    type Output = Result<Guess, GuessError>;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        if rhs == 0 {
+            return Err(GuessError::DivisionByZero);
+        }
+
+        Ok(Guess::new(self.val / rhs)?)
+    }
+}
+
+/// Calculates the remainder of dividing the guess by a number.
+/// Dividing by zero returns `GuessError::DivisionByZero`, otherwise this never fails.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::err::GuessError;
+/// 
+/// let guess = Guess::new(37).unwrap();
+/// 
+/// assert_eq!(guess % 10, Ok(Guess::new(7).unwrap()));
+/// assert_eq!(guess % 0, Err(GuessError::DivisionByZero));
+/// ```
+impl Rem<i32> for Guess {
+    type Output = Result<Guess, GuessError>;
+
+    fn rem(self, rhs: i32) -> Self::Output {
+        self.checked_rem(rhs).ok_or(GuessError::DivisionByZero)
+    }
+}
+
+/// Bitwise AND of two guesses. The result can never be bigger than either of the values,
+/// so it is always a valid guess.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// let guess = Guess::new(60).unwrap() & Guess::new(100).unwrap();
+/// 
+/// assert_eq!(guess.get(), 36);
+/// ```
+impl BitAnd for Guess {
+    type Output = Guess;
+
+    fn bitand(self, rhs: Guess) -> Self::Output {
+        Guess { val: self.val & rhs.val }
+    }
+}
+
+/// Bitwise OR of two guesses, failing if the result is bigger than 100.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert_eq!(Guess::new(32).unwrap() | Guess::new(8).unwrap(), Ok(Guess::new(40).unwrap()));
+/// assert!((Guess::new(63).unwrap() | Guess::new(64).unwrap()).is_err());
+/// ```
+impl BitOr for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn bitor(self, rhs: Guess) -> Self::Output {
+        Guess::new(self.val | rhs.val)
+    }
+}
+
+/// Bitwise XOR of two guesses, failing if the result is bigger than 100.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert_eq!(Guess::new(50).unwrap() ^ Guess::new(40).unwrap(), Ok(Guess::new(26).unwrap()));
+/// assert!((Guess::new(100).unwrap() ^ Guess::new(27).unwrap()).is_err());
+/// ```
+impl BitXor for Guess {
+    type Output = Result<Guess, GuessRangeError>;
+
+    fn bitxor(self, rhs: Guess) -> Self::Output {
+        Guess::new(self.val ^ rhs.val)
+    }
+}
+
+/// The complement of the guess, `100 - val`, the same as `complement()`.
+/// This is a negation over the (0..101) range, not a bitwise negation:
+/// the result is always a valid guess, mirrored across the midpoint.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// 
+/// assert_eq!(!Guess::new(30).unwrap(), Guess::new(70).unwrap());
+/// assert_eq!(!Guess::MIN, Guess::MAX);
+/// ```
+impl Not for Guess {
+    type Output = Guess;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saturating_add_test() {
+        assert_eq!(Guess::MAX.saturating_add(1), Guess::MAX);
+        assert_eq!(Guess::MIN.saturating_add(i32::MAX), Guess::MAX);
+        assert_eq!(Guess::MAX.saturating_add(i32::MIN), Guess::MIN);
+        assert_eq!(Guess::new(40).unwrap().saturating_add(2).get(), 42);
+        assert_eq!(Guess::new(40).unwrap().saturating_add(-2).get(), 38);
+    }
+
+    #[test]
+    fn saturating_sub_test() {
+        assert_eq!(Guess::MIN.saturating_sub(1), Guess::MIN);
+        assert_eq!(Guess::MAX.saturating_sub(i32::MAX), Guess::MIN);
+        assert_eq!(Guess::MIN.saturating_sub(i32::MIN), Guess::MAX);
+        assert_eq!(Guess::new(40).unwrap().saturating_sub(2).get(), 38);
+    }
+
+    #[test]
+    fn checked_add_test() {
+        assert_eq!(Guess::new(99).unwrap().checked_add(1), Some(Guess::MAX));
+        assert_eq!(Guess::MAX.checked_add(1), None);
+        assert_eq!(Guess::MAX.checked_add(-100), Some(Guess::MIN));
+        assert_eq!(Guess::MAX.checked_add(i32::MAX), None);
+        assert_eq!(Guess::MAX.checked_add(i32::MIN), None);
+    }
+
+    #[test]
+    fn checked_sub_test() {
+        assert_eq!(Guess::new(1).unwrap().checked_sub(1), Some(Guess::MIN));
+        assert_eq!(Guess::MIN.checked_sub(1), None);
+        assert_eq!(Guess::MIN.checked_sub(-100), Some(Guess::MAX));
+        assert_eq!(Guess::MIN.checked_sub(i32::MAX), None);
+        assert_eq!(Guess::MAX.checked_sub(i32::MIN), None);
+    }
+
+    #[test]
+    fn wrapping_add_test() {
+        let guess = Guess::new(30).unwrap();
+
+        assert_eq!(Guess::MAX.wrapping_add(1), Guess::MIN);
+        assert_eq!(guess.wrapping_add(0), guess);
+        assert_eq!(guess.wrapping_add(202 + 1), guess.wrapping_add(1));
+        assert_eq!(guess.wrapping_add(-31), Guess::MAX);
+        assert_eq!(guess.wrapping_add(i32::MAX), Guess::new_modular(30 + i32::MAX.rem_euclid(101)));
+        assert_eq!(guess.wrapping_add(i32::MIN), Guess::new_modular(30 + i32::MIN.rem_euclid(101)));
+    }
+
+    #[test]
+    fn wrapping_sub_test() {
+        let guess = Guess::new(30).unwrap();
+
+        assert_eq!(Guess::MIN.wrapping_sub(1), Guess::MAX);
+        assert_eq!(guess.wrapping_sub(0), guess);
+        assert_eq!(guess.wrapping_sub(101), guess);
+        assert_eq!(guess.wrapping_sub(-71), Guess::MIN);
+        assert_eq!(guess.wrapping_sub(i32::MIN).wrapping_add(i32::MIN), guess);
+    }
+
+    #[test]
+    fn wrapping_add_guess_test() {
+        assert_eq!(Guess::MAX.wrapping_add_guess(&Guess::MAX).get(), 99);
+        assert_eq!(Guess::MIN.wrapping_add_guess(&Guess::MAX), Guess::MAX);
+    }
+
+    #[test]
+    fn add_test() {
+        let guess = Guess::new(50).unwrap();
+
+        assert_eq!((guess + 10).unwrap(), Guess::new(60).unwrap());
+        assert_eq!((&guess + 10).unwrap(), Guess::new(60).unwrap());
+        assert_eq!(guess + -50, Ok(Guess::MIN));
+        assert!((Guess::new(99).unwrap() + 5).is_err());
+        assert_eq!(guess + i32::MAX, Err(GuessRangeError(i32::MAX)));
+        assert_eq!(guess + Guess::new(50).unwrap(), Ok(Guess::MAX));
+        assert_eq!(guess + Guess::new(51).unwrap(), Err(GuessRangeError(101)));
+    }
+
+    #[test]
+    fn add_question_mark_test() {
+        fn total(guesses: &[Guess]) -> Result<Guess, GuessRangeError> {
+            let mut sum = Guess::MIN;
+            for guess in guesses {
+                sum = (sum + *guess)?;
+            }
+            Ok(sum)
+        }
+
+        let small = [Guess::new(10).unwrap(), Guess::new(20).unwrap()];
+        let large = [Guess::new(60).unwrap(), Guess::new(70).unwrap()];
+
+        assert_eq!(total(&small), Ok(Guess::new(30).unwrap()));
+        assert_eq!(total(&large), Err(GuessRangeError(130)));
+    }
+
+    #[test]
+    fn sub_test() {
+        let guess = Guess::new(50).unwrap();
+
+        assert_eq!(guess - 10, Ok(Guess::new(40).unwrap()));
+        assert_eq!(&guess - 10, Ok(Guess::new(40).unwrap()));
+        assert_eq!(Guess::new(5).unwrap() - 10, Err(GuessRangeError(-5)));
+        assert_eq!(guess - 50, Ok(Guess::MIN));
+        assert_eq!(guess - -50, Ok(Guess::MAX));
+        assert_eq!(guess - i32::MIN, Err(GuessRangeError(i32::MAX)));
+        assert_eq!(guess - i32::MAX, Err(GuessRangeError(50 - i32::MAX)));
+    }
+
+    #[test]
+    fn sub_guess_test() {
+        let big = Guess::new(70).unwrap();
+        let small = Guess::new(30).unwrap();
+
+        assert_eq!(big - small, Ok(Guess::new(40).unwrap()));
+        assert_eq!(small - big, Err(GuessRangeError(-40)));
+        assert_eq!(big - big, Ok(Guess::MIN));
+    }
+
+    #[test]
+    fn mul_test() {
+        assert_eq!(Guess::new(3).unwrap() * 33, Ok(Guess::new(99).unwrap()));
+        assert_eq!(Guess::new(4).unwrap() * 26, Err(GuessRangeError(104)));
+        assert_eq!(Guess::new(5).unwrap() * -1, Err(GuessRangeError(-5)));
+        assert_eq!(Guess::new(5).unwrap() * -3, Err(GuessRangeError(-15)));
+        assert_eq!(Guess::MAX * -1, Err(GuessRangeError(-100)));
+        assert_eq!(Guess::MIN * i32::MIN, Ok(Guess::MIN));
+        assert_eq!(Guess::MAX * i32::MAX, Err(GuessRangeError(i32::MAX)));
+    }
+
+    #[test]
+    fn saturating_mul_test() {
+        assert_eq!(Guess::new(4).unwrap().saturating_mul(26), Guess::MAX);
+        assert_eq!(Guess::new(5).unwrap().saturating_mul(-3), Guess::MIN);
+        assert_eq!(Guess::MAX.saturating_mul(i32::MIN), Guess::MIN);
+        assert_eq!(Guess::new(4).unwrap().saturating_mul(25), Guess::MAX);
+        assert_eq!(Guess::new(4).unwrap().saturating_mul(0), Guess::MIN);
+    }
+
+    #[test]
+    fn checked_mul_test() {
+        assert_eq!(Guess::new(4).unwrap().checked_mul(26), None);
+        assert_eq!(Guess::new(5).unwrap().checked_mul(-1), None);
+        assert_eq!(Guess::MAX.checked_mul(i32::MAX), None);
+        assert_eq!(Guess::new(20).unwrap().checked_mul(5), Some(Guess::MAX));
+    }
+
+    #[test]
+    fn div_test() {
+        assert_eq!(Guess::new(50).unwrap() / 2, Ok(Guess::new(25).unwrap()));
+        assert_eq!(Guess::MIN / 1, Ok(Guess::MIN));
+        assert_eq!(Guess::new(50).unwrap() / 0, Err(GuessError::DivisionByZero));
+        assert_eq!(Guess::new(50).unwrap() / -2, Err(GuessError::RangeError(GuessRangeError(-25))));
+        assert_eq!(Guess::MIN / -2, Ok(Guess::MIN));
+        assert_eq!(Guess::MAX / i32::MIN, Ok(Guess::MIN));
+    }
+
+    #[test]
+    fn rem_test() {
+        assert_eq!(Guess::new(37).unwrap() % 10, Ok(Guess::new(7).unwrap()));
+        assert_eq!(Guess::MAX % 7, Ok(Guess::new(2).unwrap()));
+        assert_eq!(Guess::MAX % 0, Err(GuessError::DivisionByZero));
+        assert_eq!(Guess::new(37).unwrap() % -10, Ok(Guess::new(7).unwrap()));
+        assert_eq!(Guess::new(37).unwrap() % i32::MIN, Ok(Guess::new(37).unwrap()));
+    }
+
+    #[test]
+    fn checked_div_rem_test() {
+        assert_eq!(Guess::MAX.checked_div(3), Some(Guess::new(33).unwrap()));
+        assert_eq!(Guess::MAX.checked_div(0), None);
+        assert_eq!(Guess::MAX.checked_rem(3), Some(Guess::new(1).unwrap()));
+        assert_eq!(Guess::MAX.checked_rem(0), None);
+        assert_eq!(Guess::MAX.saturating_div(-1), Guess::MIN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn saturating_div_by_zero_test() {
+        let _ = Guess::MAX.saturating_div(0);
+    }
+
+    #[test]
+    fn bitand_test() {
+        assert_eq!(Guess::new(60).unwrap() & Guess::new(100).unwrap(), Guess::new(36).unwrap());
+        assert_eq!(Guess::MAX & Guess::MAX, Guess::MAX);
+        assert_eq!(Guess::MAX & Guess::MIN, Guess::MIN);
+
+        for a in 0..=100 {
+            for b in 0..=100 {
+                let result = Guess::new(a).unwrap() & Guess::new(b).unwrap();
+                assert!(result.get() <= a.min(b));
+            }
+        }
+    }
+
+    #[test]
+    fn bitor_bitxor_test() {
+        assert_eq!(Guess::new(63).unwrap() | Guess::new(64).unwrap(), Err(GuessRangeError(127)));
+        assert_eq!(Guess::new(36).unwrap() | Guess::new(64).unwrap(), Ok(Guess::MAX));
+        assert_eq!(Guess::new(50).unwrap() ^ Guess::new(40).unwrap(), Ok(Guess::new(26).unwrap()));
+        assert_eq!(Guess::MAX ^ Guess::MAX, Ok(Guess::MIN));
+        assert_eq!(Guess::MAX ^ Guess::new(27).unwrap(), Err(GuessRangeError(127)));
+    }
+
+    #[test]
+    fn diff_test() {
+        let big = Guess::new(70).unwrap();
+        let small = Guess::new(30).unwrap();
+
+        assert_eq!(big.abs_diff(&small), 40);
+        assert_eq!(small.abs_diff(&big), 40);
+        assert_eq!(big.abs_diff(&big), 0);
+        assert_eq!(Guess::MAX.abs_diff(&Guess::MIN), 100);
+
+        assert_eq!(big.signed_diff(&small), 40);
+        assert_eq!(small.signed_diff(&big), -40);
+        assert_eq!(big.signed_diff(&big), 0);
+    }
+
+    #[test]
+    fn abs_diff_symmetry_test() {
+        for a in (0..=100).step_by(7) {
+            for b in (0..=100).step_by(3) {
+                let (a, b) = (Guess::new(a).unwrap(), Guess::new(b).unwrap());
+
+                assert_eq!(a.abs_diff(&b), b.abs_diff(&a));
+                assert_eq!(a.abs_diff(&b), a.signed_diff(&b).unsigned_abs());
+            }
+        }
+    }
+
+    #[test]
+    fn not_test() {
+        assert_eq!(!Guess::MIN, Guess::MAX);
+        assert_eq!(!Guess::MAX, Guess::MIN);
+        assert_eq!(!Guess::MIDPOINT, Guess::MIDPOINT);
+
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+
+            assert_eq!(!!guess, guess);
+            assert_eq!((!guess).get(), 100 - val);
+            assert_eq!(!guess, guess.complement());
+        }
+    }
+}
@@ -0,0 +1,559 @@
+//! Methods describing where a guess lies in the range and relative to other guesses.
+
+use crate::err::GuessRangeError;
+use crate::{Guess, GUESS_RANGE_MAX, GUESS_RANGE_MIN};
+
+impl Guess {
+    /// Gets the value symmetric to this one across the midpoint, `100 - val`.
+    /// The operation is total, every guess has a valid complement.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(30).unwrap().complement(), Guess::new(70).unwrap());
+    /// assert_eq!(Guess::MIDPOINT.complement(), Guess::MIDPOINT);
+    /// ```
+    pub fn complement(&self) -> Guess {
+        Guess { val: GUESS_RANGE_MAX - self.val }
+    }
+
+    /// Calculates the distance between the guess and its complement.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(30).unwrap().distance_from_complement(), 40);
+    /// assert_eq!(Guess::MIN.distance_from_complement(), 100);
+    /// ```
+    pub fn distance_from_complement(&self) -> u32 {
+        self.abs_diff(&self.complement())
+    }
+
+    /// Calculates the midpoint between two guesses, rounding down.
+    /// This is the core step of a binary search: with rounding down,
+    /// the midpoint of `lo` and `lo + 1` is `lo` itself.
+    /// Use `Guess::midpoint_ceil(a: &Guess, b: &Guess)` for rounding up instead.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let lo = Guess::new(0).unwrap();
+    /// let hi = Guess::new(1).unwrap();
+    /// 
+    /// assert_eq!(Guess::midpoint(&Guess::MIN, &Guess::MAX).get(), 50);
+    /// assert_eq!(Guess::midpoint(&lo, &hi), lo);
+    /// ```
+    pub fn midpoint(a: &Guess, b: &Guess) -> Guess {
+        Guess { val: (a.val + b.val) / 2 }
+    }
+
+    /// Calculates the midpoint between two guesses, rounding up.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let lo = Guess::new(0).unwrap();
+    /// let hi = Guess::new(1).unwrap();
+    /// 
+    /// assert_eq!(Guess::midpoint_ceil(&lo, &hi), hi);
+    /// ```
+    pub fn midpoint_ceil(a: &Guess, b: &Guess) -> Guess {
+        Guess { val: (a.val + b.val + 1) / 2 }
+    }
+
+    /// Linearly interpolates between two guesses, rounding to the nearest integer.
+    /// `t = 0.0` gives `a` and `t = 1.0` gives `b`.
+    /// An error will be returned when `t` is outside `[0.0, 1.0]`, NaN or infinite,
+    /// since extrapolating can leave the valid range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let a = Guess::new(20).unwrap();
+    /// let b = Guess::new(80).unwrap();
+    /// 
+    /// assert_eq!(Guess::interpolate(&a, &b, 0.25), Ok(Guess::new(35).unwrap()));
+    /// assert!(Guess::interpolate(&a, &b, 1.1).is_err());
+    /// ```
+    pub fn interpolate(a: &Guess, b: &Guess, t: f64) -> Result<Guess, GuessRangeError> {
+        let val = a.val.saturating_add(crate::float::round((b.val - a.val) as f64 * t) as i32);
+
+        if !(0.0..=1.0).contains(&t) {
+            return Err(GuessRangeError(val));
+        }
+
+        Guess::new(val)
+    }
+
+    /// Finds how far `mid` is between `a` and `b`, the inverse of `interpolate()`.
+    /// The result is clamped to `[0.0, 1.0]`, and `None` is returned when `a` and `b` are equal.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let a = Guess::new(20).unwrap();
+    /// let b = Guess::new(80).unwrap();
+    /// 
+    /// assert_eq!(Guess::proportion_between(&a, &b, &Guess::new(35).unwrap()), Some(0.25));
+    /// assert_eq!(Guess::proportion_between(&a, &a, &b), None);
+    /// ```
+    pub fn proportion_between(a: &Guess, b: &Guess, mid: &Guess) -> Option<f64> {
+        if a == b {
+            return None;
+        }
+
+        let t = (mid.val - a.val) as f64 / (b.val - a.val) as f64;
+
+        Some(t.clamp(0.0, 1.0))
+    }
+
+    /// Gets the ratio between this guess and `other`, or `None` when `other` is zero.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(25).unwrap();
+    /// 
+    /// assert_eq!(guess.ratio(&Guess::new(50).unwrap()), Some(0.5));
+    /// assert_eq!(guess.ratio(&Guess::MIN), None);
+    /// ```
+    pub fn ratio(&self, other: &Guess) -> Option<f64> {
+        if other.val == 0 {
+            return None;
+        }
+
+        Some(self.val as f64 / other.val as f64)
+    }
+
+    /// Gets this guess as a percentage of `other`, or `None` when `other` is zero.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(25).unwrap();
+    /// 
+    /// assert_eq!(guess.percentage_of(&Guess::new(50).unwrap()), Some(50.0));
+    /// ```
+    pub fn percentage_of(&self, other: &Guess) -> Option<f64> {
+        self.ratio(other).map(|r| r * 100.0)
+    }
+
+    /// Checks if two guesses are at most `tolerance` apart,
+    /// useful for game modes rewarding "close enough" answers.
+    /// A tolerance of `0` is the same as checking for equality.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(50).unwrap();
+    /// 
+    /// assert!(guess.approx_eq(&Guess::new(55).unwrap(), 5));
+    /// assert!(!guess.approx_eq(&Guess::new(56).unwrap(), 5));
+    /// ```
+    pub fn approx_eq(&self, other: &Guess, tolerance: u32) -> bool {
+        self.abs_diff(other) <= tolerance
+    }
+
+    /// Checks if the guess is at most `radius` away from `center`.
+    /// This is the same as `approx_eq`, reading more naturally in game code.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let my_guess = Guess::new(45).unwrap();
+    /// 
+    /// assert!(my_guess.within(&secret, 5));
+    /// ```
+    pub fn within(&self, center: &Guess, radius: u32) -> bool {
+        self.approx_eq(center, radius)
+    }
+
+    /// Checks if two guesses are exactly equal, pairing visually with `approx_eq`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(42).unwrap();
+    /// 
+    /// assert!(guess.is_exact(&Guess::new(42).unwrap()));
+    /// ```
+    pub fn is_exact(&self, other: &Guess) -> bool {
+        self == other
+    }
+
+    /// Clamps the guess to the subrange between `lo` and `hi`, both inclusive.
+    /// The bounds are expected in order, `hi` wins if `lo` is larger than it.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let (lo, hi) = (Guess::new(20).unwrap(), Guess::new(40).unwrap());
+    /// 
+    /// assert_eq!(Guess::new(30).unwrap().clamp_to_subrange(&lo, &hi), Guess::new(30).unwrap());
+    /// assert_eq!(Guess::new(90).unwrap().clamp_to_subrange(&lo, &hi), hi);
+    /// ```
+    pub fn clamp_to_subrange(&self, lo: &Guess, hi: &Guess) -> Guess {
+        Guess { val: self.val.max(lo.val).min(hi.val) }
+    }
+
+    /// Checks if the guess is between `lo` and `hi`, both inclusive.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let (lo, hi) = (Guess::new(20).unwrap(), Guess::new(40).unwrap());
+    /// 
+    /// assert!(Guess::new(20).unwrap().is_in_subrange(&lo, &hi));
+    /// assert!(!Guess::new(41).unwrap().is_in_subrange(&lo, &hi));
+    /// ```
+    pub fn is_in_subrange(&self, lo: &Guess, hi: &Guess) -> bool {
+        lo.val <= self.val && self.val <= hi.val
+    }
+
+    /// Calculates the distance to the closest value between `lo` and `hi`,
+    /// which is `0` when the guess is already in the subrange.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let (lo, hi) = (Guess::new(20).unwrap(), Guess::new(40).unwrap());
+    /// 
+    /// assert_eq!(Guess::new(30).unwrap().distance_to_subrange(&lo, &hi), 0);
+    /// assert_eq!(Guess::new(5).unwrap().distance_to_subrange(&lo, &hi), 15);
+    /// ```
+    pub fn distance_to_subrange(&self, lo: &Guess, hi: &Guess) -> u32 {
+        if self.val < lo.val {
+            self.abs_diff(lo)
+        } else if self.val > hi.val {
+            self.abs_diff(hi)
+        } else {
+            0
+        }
+    }
+
+    /// Gets the two adjacent valid values as `(prev, next)`.
+    /// `prev` is `None` for `0` and `next` is `None` for `100`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let (prev, next) = Guess::new(50).unwrap().neighbors();
+    /// 
+    /// assert_eq!(prev, Some(Guess::new(49).unwrap()));
+    /// assert_eq!(next, Some(Guess::new(51).unwrap()));
+    /// assert_eq!(Guess::MIN.neighbors().0, None);
+    /// ```
+    pub fn neighbors(&self) -> (Option<Guess>, Option<Guess>) {
+        (self.prev(), self.next_val())
+    }
+
+    /// Gets the previous valid value, or `None` for `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(1).unwrap().prev(), Some(Guess::MIN));
+    /// assert_eq!(Guess::MIN.prev(), None);
+    /// ```
+    pub fn prev(&self) -> Option<Guess> {
+        self.step(-1)
+    }
+
+    /// Gets the next valid value, or `None` for `100`.
+    /// Named `next_val` to avoid confusion with `Iterator::next`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(99).unwrap().next_val(), Some(Guess::MAX));
+    /// assert_eq!(Guess::MAX.next_val(), None);
+    /// ```
+    pub fn next_val(&self) -> Option<Guess> {
+        self.step(1)
+    }
+
+    /// Steps `direction` values away from the guess: positive directions step up
+    /// and negative ones step down. Returns `None` when stepping out of the range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(50).unwrap();
+    /// 
+    /// assert_eq!(guess.step(-1), Some(Guess::new(49).unwrap()));
+    /// assert_eq!(guess.step(5), Some(Guess::new(55).unwrap()));
+    /// assert_eq!(guess.step(51), None);
+    /// ```
+    pub fn step(&self, direction: i32) -> Option<Guess> {
+        self.checked_add(direction)
+    }
+
+    /// Checks if the guess is at either end of the range, `0` or `100`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert!(Guess::MAX.is_boundary());
+    /// assert!(!Guess::new(1).unwrap().is_boundary());
+    /// ```
+    #[inline]
+    pub fn is_boundary(&self) -> bool {
+        self.is_min() || self.is_max()
+    }
+
+    /// Checks if the guess holds the smallest valid value, `0`.
+    #[inline]
+    pub fn is_min(&self) -> bool {
+        self.val == GUESS_RANGE_MIN
+    }
+
+    /// Checks if the guess holds the largest valid value, `100`.
+    #[inline]
+    pub fn is_max(&self) -> bool {
+        self.val == GUESS_RANGE_MAX
+    }
+
+    /// Checks if the guess is strictly inside the range, the negation of `is_boundary()`.
+    #[inline]
+    pub fn is_interior(&self) -> bool {
+        !self.is_boundary()
+    }
+
+    /// Calculates the distance to the closer of the two boundaries.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(50).unwrap().distance_to_nearest_boundary(), 50);
+    /// assert_eq!(Guess::new(97).unwrap().distance_to_nearest_boundary(), 3);
+    /// ```
+    #[inline]
+    pub fn distance_to_nearest_boundary(&self) -> u32 {
+        self.abs_diff(&Guess::MIN).min(self.abs_diff(&Guess::MAX))
+    }
+
+    /// Calculates the distance from the midpoint of the range, `50`,
+    /// which measures how extreme the guess is.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(75).unwrap().abs_distance_from_midpoint(), 25);
+    /// assert_eq!(Guess::MIN.abs_distance_from_midpoint(), 50);
+    /// ```
+    pub fn abs_distance_from_midpoint(&self) -> u32 {
+        self.abs_diff(&Guess::MIDPOINT)
+    }
+
+    /// Checks if the guess is strictly above the midpoint of the range.
+    pub fn is_above_midpoint(&self) -> bool {
+        *self > Guess::MIDPOINT
+    }
+
+    /// Checks if the guess is strictly below the midpoint of the range.
+    pub fn is_below_midpoint(&self) -> bool {
+        *self < Guess::MIDPOINT
+    }
+
+    /// Checks if the guess is exactly the midpoint of the range, `50`.
+    pub fn is_midpoint(&self) -> bool {
+        *self == Guess::MIDPOINT
+    }
+
+    /// Calculates the distance from the provided value.
+    /// An error will be returned if the value is outside (0..101) range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let guess = Guess::new(30).unwrap();
+    /// 
+    /// assert_eq!(guess.distance_from_value(42), Ok(12));
+    /// assert!(guess.distance_from_value(420).is_err());
+    /// ```
+    pub fn distance_from_value(&self, target: i32) -> Result<u32, GuessRangeError> {
+        Ok(self.abs_diff(&Guess::new(target)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complement_test() {
+        assert_eq!(Guess::new(30).unwrap().complement(), Guess::new(70).unwrap());
+        assert_eq!(Guess::new(50).unwrap().complement(), Guess::new(50).unwrap());
+        assert_eq!(Guess::MIN.complement(), Guess::MAX);
+
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+
+            assert_eq!(guess.complement().complement(), guess);
+            assert_eq!(guess.distance_from_complement(), (2 * val - 100).unsigned_abs());
+        }
+    }
+
+    #[test]
+    fn midpoint_test() {
+        let zero = Guess::new(0).unwrap();
+        let one = Guess::new(1).unwrap();
+
+        assert_eq!(Guess::midpoint(&Guess::MIN, &Guess::MAX), Guess::new(50).unwrap());
+        assert_eq!(Guess::midpoint(&zero, &one), zero);
+        assert_eq!(Guess::midpoint_ceil(&zero, &one), one);
+        assert_eq!(Guess::midpoint_ceil(&Guess::MAX, &Guess::MAX), Guess::MAX);
+
+        for (a, b) in [(3, 8), (0, 100), (99, 100), (42, 42)] {
+            let (a, b) = (Guess::new(a).unwrap(), Guess::new(b).unwrap());
+
+            assert_eq!(Guess::midpoint(&a, &b), Guess::midpoint(&b, &a));
+            assert_eq!(Guess::midpoint_ceil(&a, &b), Guess::midpoint_ceil(&b, &a));
+            assert!(Guess::midpoint(&a, &b) <= Guess::midpoint_ceil(&a, &b));
+        }
+    }
+
+    #[test]
+    fn interpolate_test() {
+        let a = Guess::new(20).unwrap();
+        let b = Guess::new(80).unwrap();
+
+        assert_eq!(Guess::interpolate(&Guess::MIN, &Guess::MAX, 0.5), Ok(Guess::new(50).unwrap()));
+        assert_eq!(Guess::interpolate(&a, &b, 0.25), Ok(Guess::new(35).unwrap()));
+        assert_eq!(Guess::interpolate(&b, &a, 0.25), Ok(Guess::new(65).unwrap()));
+        assert_eq!(Guess::interpolate(&a, &b, 0.0), Ok(a));
+        assert_eq!(Guess::interpolate(&a, &b, 1.0), Ok(b));
+        assert_eq!(Guess::interpolate(&a, &b, 1.1), Err(GuessRangeError(86)));
+        assert!(Guess::interpolate(&a, &b, -0.1).is_err());
+        assert!(Guess::interpolate(&a, &b, f64::NAN).is_err());
+        assert!(Guess::interpolate(&a, &b, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let guess = Guess::new(50).unwrap();
+
+        assert!(guess.approx_eq(&Guess::new(55).unwrap(), 5));
+        assert!(guess.approx_eq(&Guess::new(45).unwrap(), 5));
+        assert!(!guess.approx_eq(&Guess::new(56).unwrap(), 5));
+        assert!(guess.within(&Guess::new(40).unwrap(), 10));
+        assert!(!guess.within(&Guess::new(39).unwrap(), 10));
+
+        for val in 0..=100 {
+            let other = Guess::new(val).unwrap();
+
+            assert_eq!(guess.approx_eq(&other, 0), guess == other);
+            assert_eq!(guess.is_exact(&other), guess == other);
+        }
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(Guess::MIN.neighbors(), (None, Some(guess(1))));
+        assert_eq!(Guess::MAX.neighbors(), (Some(guess(99)), None));
+        assert_eq!(guess(50).neighbors(), (Some(guess(49)), Some(guess(51))));
+        assert_eq!(guess(50).step(0), Some(guess(50)));
+        assert_eq!(guess(50).step(-50), Some(Guess::MIN));
+        assert_eq!(guess(50).step(-51), None);
+        assert_eq!(guess(50).step(i32::MIN), None);
+    }
+
+    #[test]
+    fn boundary_test() {
+        assert!(Guess::MIN.is_boundary() && Guess::MIN.is_min() && !Guess::MIN.is_max());
+        assert!(Guess::MAX.is_boundary() && Guess::MAX.is_max() && !Guess::MAX.is_min());
+        assert!(!Guess::MIN.is_interior());
+
+        for val in 1..=99 {
+            let guess = Guess::new(val).unwrap();
+
+            assert!(!guess.is_boundary());
+            assert!(guess.is_interior());
+            assert_eq!(guess.distance_to_nearest_boundary(), val.min(100 - val) as u32);
+        }
+
+        assert_eq!(Guess::MIN.distance_to_nearest_boundary(), 0);
+        assert_eq!(Guess::MAX.distance_to_nearest_boundary(), 0);
+    }
+
+    #[test]
+    fn midpoint_position_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(guess(50).abs_distance_from_midpoint(), 0);
+        assert_eq!(guess(0).abs_distance_from_midpoint(), 50);
+        assert_eq!(guess(100).abs_distance_from_midpoint(), 50);
+        assert_eq!(guess(75).abs_distance_from_midpoint(), 25);
+
+        assert!(guess(50).is_midpoint() && !guess(50).is_above_midpoint() && !guess(50).is_below_midpoint());
+        assert!(guess(51).is_above_midpoint() && !guess(51).is_midpoint());
+        assert!(guess(49).is_below_midpoint() && !guess(49).is_above_midpoint());
+    }
+
+    #[test]
+    fn distance_from_value_test() {
+        let guess = Guess::new(30).unwrap();
+
+        assert_eq!(guess.distance_from_value(30), Ok(0));
+        assert_eq!(guess.distance_from_value(0), Ok(30));
+        assert_eq!(guess.distance_from_value(100), Ok(70));
+        assert_eq!(guess.distance_from_value(-1), Err(GuessRangeError(-1)));
+        assert_eq!(guess.distance_from_value(101), Err(GuessRangeError(101)));
+    }
+
+    #[test]
+    fn ratio_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(guess(25).ratio(&guess(50)), Some(0.5));
+        assert_eq!(guess(100).ratio(&guess(25)), Some(4.0));
+        assert_eq!(guess(0).ratio(&guess(50)), Some(0.0));
+        assert_eq!(guess(50).ratio(&guess(0)), None);
+        assert_eq!(guess(25).percentage_of(&guess(50)), Some(50.0));
+        assert_eq!(guess(50).percentage_of(&guess(0)), None);
+    }
+
+    #[test]
+    fn proportion_between_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(Guess::proportion_between(&guess(0), &guess(100), &guess(50)), Some(0.5));
+        assert_eq!(Guess::proportion_between(&guess(100), &guess(0), &guess(25)), Some(0.75));
+        assert_eq!(Guess::proportion_between(&guess(20), &guess(40), &guess(10)), Some(0.0));
+        assert_eq!(Guess::proportion_between(&guess(20), &guess(40), &guess(90)), Some(1.0));
+
+        for val in [0, 50, 100] {
+            assert_eq!(Guess::proportion_between(&guess(50), &guess(50), &guess(val)), None);
+        }
+    }
+
+    #[test]
+    fn subrange_test() {
+        let guess = |val| Guess::new(val).unwrap();
+        let (lo, hi) = (guess(20), guess(40));
+
+        for val in 20..=40 {
+            assert_eq!(guess(val).clamp_to_subrange(&lo, &hi), guess(val));
+        }
+
+        assert_eq!(guess(0).clamp_to_subrange(&lo, &hi), lo);
+        assert_eq!(guess(19).clamp_to_subrange(&lo, &hi), lo);
+        assert_eq!(guess(41).clamp_to_subrange(&lo, &hi), hi);
+        assert_eq!(Guess::MAX.clamp_to_subrange(&lo, &hi), hi);
+        assert_eq!(guess(7).clamp_to_subrange(&guess(7), &guess(7)), guess(7));
+
+        assert_eq!(guess(5).distance_to_subrange(&lo, &hi), 15);
+        assert_eq!(guess(41).distance_to_subrange(&lo, &hi), 1);
+
+        for val in 0..=100 {
+            let g = guess(val);
+            let distance = g.distance_to_subrange(&lo, &hi);
+
+            assert_eq!(g.is_in_subrange(&lo, &hi), distance == 0);
+            assert_eq!(distance, g.abs_diff(&g.clamp_to_subrange(&lo, &hi)));
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! The most commonly used items, for glob importing.
+//! 
+//! # Example
+//! ```
+//! use guessing_utils::prelude::*;
+//! 
+//! let mut session = GuessSession::new(guess!(42), 5);
+//! 
+//! assert_eq!(session.attempt(guess!(50)), GuessResult::TooHigh { distance: 8 });
+//! assert!(matches!(Guess::parse("150"), Err(GuessError::RangeError(_))));
+//! ```
+
+pub use crate::err::{GuessError, GuessRangeError};
+pub use crate::guess;
+pub use crate::hint::GuessHint;
+pub use crate::{Guess, GuessList, GuessRange, GuessResult, GuessSession, GuessSessionConfig};
@@ -0,0 +1,577 @@
+//! Functions generating random guesses.
+
+use crate::err::{GuessError, WeightError};
+use crate::{Guess, GUESS_RANGE_MAX, GUESS_RANGE_MIN, GUESS_RANGE_SIZE};
+use alloc::vec::Vec;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+/// Generates a `Guess` object together with a randomly generated number in (0..101) range put inside the object.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::gen_random;
+/// 
+/// use std::cmp::Ordering;
+/// 
+/// let random_guess = gen_random();
+/// let my_guess = Guess::new(50).unwrap();
+/// 
+/// match random_guess.cmp(&my_guess) {
+///     Ordering::Equal => println!("I guessed the number!"),
+///     _ => (),
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn gen_random() -> Guess {
+    gen_random_with_rng(&mut rand::thread_rng())
+}
+
+/// Generates a random guess using the provided random number generator.
+/// This allows injecting a seeded generator for tests, or a cryptographically secure one.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_with_rng;
+/// 
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// 
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let guess = gen_random_with_rng(&mut rng);
+/// 
+/// assert!(guess.get() <= 100);
+/// ```
+pub fn gen_random_with_rng<R: Rng>(rng: &mut R) -> Guess {
+    Guess::new(rng.gen_range(GUESS_RANGE_MIN..=GUESS_RANGE_MAX)).unwrap()
+}
+
+/// Generates `n` random guesses using the provided random number generator.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_n_with_rng;
+/// 
+/// let guesses = gen_random_n_with_rng(&mut rand::thread_rng(), 10);
+/// 
+/// assert_eq!(guesses.len(), 10);
+/// ```
+pub fn gen_random_n_with_rng<R: Rng>(rng: &mut R, n: usize) -> Vec<Guess> {
+    (0..n).map(|_| gen_random_with_rng(rng)).collect()
+}
+
+/// Generates `n` independent random guesses, which may repeat.
+/// Use `gen_random_n_with_rng` to provide the random number generator.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_batch;
+/// 
+/// assert_eq!(gen_random_batch(5).len(), 5);
+/// ```
+#[cfg(feature = "std")]
+pub fn gen_random_batch(n: usize) -> Vec<Guess> {
+    gen_random_n_with_rng(&mut rand::thread_rng(), n)
+}
+
+/// Generates `n` distinct random guesses.
+/// An error will be returned if `n` is greater than 101, the number of valid values.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_unique_batch;
+/// 
+/// let batch = gen_random_unique_batch(10).unwrap();
+/// 
+/// assert_eq!(batch.len(), 10);
+/// assert!(gen_random_unique_batch(102).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn gen_random_unique_batch(n: usize) -> Result<Vec<Guess>, GuessError> {
+    gen_random_unique_batch_with_rng(&mut rand::thread_rng(), n)
+}
+
+/// Generates `n` distinct random guesses using the provided random number generator.
+/// An error will be returned if `n` is greater than 101, the number of valid values.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_unique_batch_with_rng;
+/// 
+/// let batch = gen_random_unique_batch_with_rng(&mut rand::thread_rng(), 101).unwrap();
+/// 
+/// assert_eq!(batch.len(), 101);
+/// ```
+pub fn gen_random_unique_batch_with_rng<R: Rng>(rng: &mut R, n: usize) -> Result<Vec<Guess>, GuessError> {
+    if n > GUESS_RANGE_SIZE {
+        return Err(GuessError::InsufficientValues { requested: n, available: GUESS_RANGE_SIZE });
+    }
+
+    let mut values: Vec<i32> = (GUESS_RANGE_MIN..=GUESS_RANGE_MAX).collect();
+
+    // A partial Fisher-Yates shuffle, only the first `n` positions need to be random.
+    for i in 0..n {
+        let j = rng.gen_range(i..GUESS_RANGE_SIZE);
+        values.swap(i, j);
+    }
+
+    Ok(values[..n].iter().map(|&val| Guess { val }).collect())
+}
+
+/// Generates a random guess that is not in `excluded`.
+/// Returns `None` when every valid value is excluded.
+/// # Example
+/// ```
+/// use guessing_utils::{gen_random_excluding, Guess};
+/// 
+/// let excluded = [Guess::MIN, Guess::MAX];
+/// let guess = gen_random_excluding(&excluded).unwrap();
+/// 
+/// assert!(!excluded.contains(&guess));
+/// ```
+#[cfg(feature = "std")]
+pub fn gen_random_excluding(excluded: &[Guess]) -> Option<Guess> {
+    gen_random_excluding_with_rng(&mut rand::thread_rng(), excluded)
+}
+
+/// Generates a random guess that is not in `excluded` using the provided random number generator.
+/// Returns `None` when every valid value is excluded.
+/// # Example
+/// ```
+/// use guessing_utils::{gen_random_excluding_with_rng, Guess};
+/// 
+/// let excluded: Vec<Guess> = (1..=100).map(|val| Guess::new(val).unwrap()).collect();
+/// 
+/// assert_eq!(gen_random_excluding_with_rng(&mut rand::thread_rng(), &excluded), Some(Guess::MIN));
+/// ```
+pub fn gen_random_excluding_with_rng<R: Rng>(rng: &mut R, excluded: &[Guess]) -> Option<Guess> {
+    let mut is_excluded = [false; GUESS_RANGE_SIZE];
+    for guess in excluded {
+        is_excluded[guess.val as usize] = true;
+    }
+
+    let eligible: Vec<Guess> = (GUESS_RANGE_MIN..=GUESS_RANGE_MAX)
+        .map(|val| Guess { val })
+        .filter(|guess| !is_excluded[guess.val as usize])
+        .collect();
+
+    eligible.choose(rng).copied()
+}
+
+/// Generates a random guess in the inclusive range from `min` to `max`.
+/// A `RangeError` will be returned if either of the bounds is outside (0..101) range,
+/// or an `InvertedRange` error if `max` is smaller than `min`.
+/// # Example
+/// ```
+/// use guessing_utils::{gen_random_range, Guess};
+/// use guessing_utils::err::GuessError;
+/// 
+/// assert_eq!(gen_random_range(50, 50), Ok(Guess::new(50).unwrap()));
+/// assert_eq!(gen_random_range(50, 40), Err(GuessError::InvertedRange { min: 50, max: 40 }));
+/// ```
+#[cfg(feature = "std")]
+pub fn gen_random_range(min: i32, max: i32) -> Result<Guess, GuessError> {
+    gen_random_range_with_rng(&mut rand::thread_rng(), min, max)
+}
+
+/// Generates a random guess in the inclusive range from `min` to `max` using the provided random number generator.
+/// A `RangeError` will be returned if either of the bounds is outside (0..101) range,
+/// or an `InvertedRange` error if `max` is smaller than `min`.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_range_with_rng;
+/// 
+/// let guess = gen_random_range_with_rng(&mut rand::thread_rng(), 10, 20).unwrap();
+/// 
+/// assert!((10..=20).contains(&guess.get()));
+/// ```
+pub fn gen_random_range_with_rng<R: Rng>(rng: &mut R, min: i32, max: i32) -> Result<Guess, GuessError> {
+    Guess::new(min)?;
+    Guess::new(max)?;
+
+    if min > max {
+        return Err(GuessError::InvertedRange { min, max });
+    }
+
+    Ok(Guess { val: rng.gen_range(min..=max) })
+}
+
+/// Generates a random guess outside the inclusive range from `excl_min` to `excl_max`.
+/// Returns `None` when the excluded range covers every valid value.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_excluding_range;
+/// 
+/// let guess = gen_random_excluding_range(10, 90).unwrap();
+/// 
+/// assert!(!(10..=90).contains(&guess.get()));
+/// assert_eq!(gen_random_excluding_range(0, 100), None);
+/// ```
+#[cfg(feature = "std")]
+pub fn gen_random_excluding_range(excl_min: i32, excl_max: i32) -> Option<Guess> {
+    gen_random_excluding_range_with_rng(&mut rand::thread_rng(), excl_min, excl_max)
+}
+
+/// Generates a random guess outside the inclusive range from `excl_min` to `excl_max`
+/// using the provided random number generator.
+/// Returns `None` when the excluded range covers every valid value.
+/// # Example
+/// ```
+/// use guessing_utils::{gen_random_excluding_range_with_rng, Guess};
+/// 
+/// assert_eq!(gen_random_excluding_range_with_rng(&mut rand::thread_rng(), 1, 100), Some(Guess::MIN));
+/// ```
+pub fn gen_random_excluding_range_with_rng<R: Rng>(rng: &mut R, excl_min: i32, excl_max: i32) -> Option<Guess> {
+    let eligible: Vec<Guess> = (GUESS_RANGE_MIN..=GUESS_RANGE_MAX)
+        .filter(|val| !(excl_min..=excl_max).contains(val))
+        .map(|val| Guess { val })
+        .collect();
+
+    eligible.choose(rng).copied()
+}
+
+/// Generates a random guess where each value is chosen with a probability proportional to its weight.
+/// The slice must hold exactly 101 non-negative weights, one for each valid value, with a positive sum.
+/// # Example
+/// ```
+/// use guessing_utils::{gen_random_weighted, Guess};
+/// 
+/// let mut weights = [0.0; 101];
+/// weights[42] = 1.0;
+/// 
+/// assert_eq!(gen_random_weighted(&weights), Ok(Guess::new(42).unwrap()));
+/// assert!(gen_random_weighted(&[1.0; 10]).is_err());
+/// ```
+#[cfg(feature = "std")]
+pub fn gen_random_weighted(weights: &[f64]) -> Result<Guess, WeightError> {
+    gen_random_weighted_with_rng(&mut rand::thread_rng(), weights)
+}
+
+/// Generates a weighted random guess using the provided random number generator.
+/// The slice must hold exactly 101 non-negative weights, one for each valid value, with a positive sum.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_weighted_with_rng;
+/// 
+/// let weights = [1.0; 101];
+/// let guess = gen_random_weighted_with_rng(&mut rand::thread_rng(), &weights).unwrap();
+/// 
+/// assert!(guess.get() <= 100);
+/// ```
+pub fn gen_random_weighted_with_rng<R: Rng>(rng: &mut R, weights: &[f64]) -> Result<Guess, WeightError> {
+    if weights.len() != GUESS_RANGE_SIZE {
+        return Err(WeightError::WrongLength(weights.len()));
+    }
+
+    if let Some((index, &weight)) = weights.iter().enumerate().find(|(_, w)| !w.is_finite() || **w < 0.0) {
+        return Err(WeightError::InvalidWeight { index, weight });
+    }
+
+    let dist = WeightedIndex::new(weights).map_err(|_| WeightError::AllWeightsZero)?;
+
+    Ok(Guess { val: dist.sample(rng) as i32 })
+}
+
+/// Generates a normally distributed random guess, rounded to the nearest integer and clamped into (0..101) range.
+/// Requires the `gaussian` feature.
+/// # Panics
+/// Panics if `std_dev` is NaN or infinite.
+/// # Example
+/// ```
+/// use guessing_utils::{gen_random_gaussian, Guess};
+/// 
+/// assert_eq!(gen_random_gaussian(42.3, 0.0), Guess::new(42).unwrap());
+/// ```
+#[cfg(all(feature = "gaussian", feature = "std"))]
+pub fn gen_random_gaussian(mean: f64, std_dev: f64) -> Guess {
+    gen_random_gaussian_with_rng(&mut rand::thread_rng(), mean, std_dev)
+}
+
+/// Generates a normally distributed random guess using the provided random number generator,
+/// rounded to the nearest integer and clamped into (0..101) range.
+/// Requires the `gaussian` feature.
+/// # Panics
+/// Panics if `std_dev` is NaN or infinite.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_gaussian_with_rng;
+/// 
+/// let guess = gen_random_gaussian_with_rng(&mut rand::thread_rng(), 50.0, 10.0);
+/// 
+/// assert!(guess.get() <= 100);
+/// ```
+#[cfg(feature = "gaussian")]
+pub fn gen_random_gaussian_with_rng<R: Rng>(rng: &mut R, mean: f64, std_dev: f64) -> Guess {
+    let dist = rand_distr::Normal::new(mean, std_dev).expect("standard deviation must be finite");
+
+    Guess::new_clamped_from_f64(dist.sample(rng))
+}
+
+/// Generates a random guess from a seed, the same seed always giving the same guess.
+/// Useful for reproducible tests of game logic.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_seeded;
+/// 
+/// assert_eq!(gen_random_seeded(42), gen_random_seeded(42));
+/// ```
+pub fn gen_random_seeded(seed: u64) -> Guess {
+    gen_random_with_rng(&mut StdRng::seed_from_u64(seed))
+}
+
+/// Generates a sequence of `n` random guesses from a seed, the same seed always giving the same sequence.
+/// # Example
+/// ```
+/// use guessing_utils::gen_random_seeded_sequence;
+/// 
+/// let sequence = gen_random_seeded_sequence(42, 10);
+/// 
+/// assert_eq!(sequence.len(), 10);
+/// assert_eq!(sequence, gen_random_seeded_sequence(42, 10));
+/// ```
+pub fn gen_random_seeded_sequence(seed: u64, n: usize) -> Vec<Guess> {
+    gen_random_n_with_rng(&mut StdRng::seed_from_u64(seed), n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::err::GuessRangeError;
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn gen_random_seeded_test() {
+        for seed in 0..100 {
+            assert_eq!(gen_random_seeded(seed), gen_random_seeded(seed));
+            assert_eq!(gen_random_seeded(seed), gen_random_seeded_sequence(seed, 1)[0]);
+        }
+
+        let values: crate::GuessSet = (0..2000).map(gen_random_seeded).collect();
+        assert!(values.len() > 90);
+    }
+
+    #[test]
+    fn gen_random_seeded_sequence_test() {
+        assert!(gen_random_seeded_sequence(7, 0).is_empty());
+        assert_eq!(gen_random_seeded_sequence(7, 50), gen_random_seeded_sequence(7, 50));
+        assert_eq!(gen_random_seeded_sequence(7, 50)[..20], gen_random_seeded_sequence(7, 20)[..]);
+        assert_ne!(gen_random_seeded_sequence(7, 50), gen_random_seeded_sequence(8, 50));
+    }
+
+    #[test]
+    fn gen_random_seeded_platform_test() {
+        let values: Vec<i32> = [0, 1, 42, u64::MAX].into_iter().map(|seed| gen_random_seeded(seed).get()).collect();
+        let sequence: Vec<i32> = gen_random_seeded_sequence(42, 5).into_iter().map(|g| g.get()).collect();
+
+        assert_eq!(values, [56, 83, 13, 18]);
+        assert_eq!(sequence, [13, 53, 25, 87, 64]);
+    }
+
+    #[test]
+    fn gen_random_test() {
+        for _ in 0..1000 {
+            assert!((0..=100).contains(&gen_random().get()));
+        }
+    }
+
+    #[test]
+    fn gen_random_with_rng_test() {
+        let mut a = StdRng::seed_from_u64(3);
+        let mut b = StdRng::seed_from_u64(3);
+
+        assert_eq!(gen_random_n_with_rng(&mut a, 20), gen_random_n_with_rng(&mut b, 20));
+        assert_eq!(gen_random_with_rng(&mut a), gen_random_with_rng(&mut b));
+        assert!(gen_random_n_with_rng(&mut a, 0).is_empty());
+    }
+
+    #[test]
+    fn gen_random_with_mock_rng_test() {
+        let mut zeros = StepRng::new(0, 0);
+        let mut high = StepRng::new(0xFF00_0000, 0);
+
+        assert_eq!(gen_random_n_with_rng(&mut zeros, 5), vec![Guess::MIN; 5]);
+        assert_eq!(gen_random_n_with_rng(&mut high, 5), vec![Guess::MAX; 5]);
+    }
+
+    #[test]
+    fn gen_random_batch_test() {
+        assert_eq!(gen_random_batch(101).len(), 101);
+        assert!(gen_random_batch(0).is_empty());
+    }
+
+    #[test]
+    fn gen_random_unique_batch_test() {
+        let batch = gen_random_unique_batch(101).unwrap();
+        let unique: crate::GuessSet = batch.iter().copied().collect();
+
+        assert_eq!(batch.len(), 101);
+        assert_eq!(unique, crate::guess_set_from_range(0, 100).unwrap());
+        assert!(gen_random_unique_batch(0).unwrap().is_empty());
+        assert_eq!(
+            gen_random_unique_batch(102),
+            Err(GuessError::InsufficientValues { requested: 102, available: 101 })
+        );
+    }
+
+    #[test]
+    fn gen_random_unique_batch_with_rng_test() {
+        let mut rng = StdRng::seed_from_u64(5);
+
+        for n in 0..=101 {
+            let batch = gen_random_unique_batch_with_rng(&mut rng, n).unwrap();
+            let unique: crate::GuessSet = batch.iter().copied().collect();
+
+            assert_eq!(unique.len(), n);
+        }
+
+        assert_eq!(
+            gen_random_unique_batch_with_rng(&mut StdRng::seed_from_u64(5), 20),
+            gen_random_unique_batch_with_rng(&mut StdRng::seed_from_u64(5), 20)
+        );
+    }
+
+    #[test]
+    fn gen_random_excluding_test() {
+        let all: Vec<Guess> = (0..=100).map(|val| Guess::new(val).unwrap()).collect();
+
+        for (i, &remaining) in all.iter().enumerate().step_by(10) {
+            let mut excluded = all.clone();
+            excluded.remove(i);
+
+            for _ in 0..20 {
+                assert_eq!(gen_random_excluding(&excluded), Some(remaining));
+            }
+        }
+
+        assert_eq!(gen_random_excluding(&all), None);
+    }
+
+    #[test]
+    fn gen_random_excluding_empty_test() {
+        let mut a = StdRng::seed_from_u64(11);
+        let mut b = StdRng::seed_from_u64(11);
+
+        assert!(gen_random_excluding(&[]).is_some());
+
+        let values: crate::GuessSet = (0..2000).filter_map(|_| gen_random_excluding_with_rng(&mut a, &[])).collect();
+        assert_eq!(values.len(), 101);
+
+        let reference = gen_random_excluding_with_rng(&mut b, &[]);
+        assert_eq!(reference, gen_random_excluding_with_rng(&mut StdRng::seed_from_u64(11), &[]));
+    }
+
+    #[test]
+    fn gen_random_excluding_duplicates_test() {
+        let excluded = [Guess::MIN, Guess::MIN, Guess::MAX, Guess::MIN, Guess::MAX];
+
+        for _ in 0..200 {
+            let guess = gen_random_excluding(&excluded).unwrap();
+
+            assert!(guess != Guess::MIN);
+            assert!(guess != Guess::MAX);
+        }
+    }
+
+    #[test]
+    fn gen_random_range_test() {
+        for _ in 0..100 {
+            assert_eq!(gen_random_range(50, 50), Ok(Guess::new(50).unwrap()));
+            assert!((40..=45).contains(&gen_random_range(40, 45).unwrap().get()));
+        }
+
+        assert_eq!(gen_random_range(50, 40), Err(GuessError::InvertedRange { min: 50, max: 40 }));
+        assert_eq!(gen_random_range(-1, 40), Err(GuessError::RangeError(GuessRangeError(-1))));
+        assert_eq!(gen_random_range(0, 101), Err(GuessError::RangeError(GuessRangeError(101))));
+    }
+
+    #[test]
+    fn gen_random_range_full_test() {
+        let mut a = StdRng::seed_from_u64(9);
+        let mut b = StdRng::seed_from_u64(9);
+
+        for _ in 0..100 {
+            assert_eq!(gen_random_range_with_rng(&mut a, 0, 100), Ok(gen_random_with_rng(&mut b)));
+        }
+    }
+
+    #[test]
+    fn gen_random_excluding_range_test() {
+        assert_eq!(gen_random_excluding_range(0, 100), None);
+        assert_eq!(gen_random_excluding_range(-10, 200), None);
+
+        for _ in 0..100 {
+            assert_eq!(gen_random_excluding_range(0, 99), Some(Guess::MAX));
+            assert!(!(20..=80).contains(&gen_random_excluding_range(20, 80).unwrap().get()));
+            assert!(gen_random_excluding_range(80, 20).is_some());
+        }
+    }
+
+    #[test]
+    fn gen_random_weighted_invalid_test() {
+        assert_eq!(gen_random_weighted(&[1.0; 100]), Err(WeightError::WrongLength(100)));
+        assert_eq!(gen_random_weighted(&[]), Err(WeightError::WrongLength(0)));
+        assert_eq!(gen_random_weighted(&[0.0; 101]), Err(WeightError::AllWeightsZero));
+
+        let mut weights = [1.0; 101];
+        weights[7] = -1.0;
+        assert_eq!(gen_random_weighted(&weights), Err(WeightError::InvalidWeight { index: 7, weight: -1.0 }));
+
+        weights[7] = f64::INFINITY;
+        assert!(matches!(gen_random_weighted(&weights), Err(WeightError::InvalidWeight { index: 7, .. })));
+
+        weights[7] = f64::NAN;
+        assert!(matches!(gen_random_weighted(&weights), Err(WeightError::InvalidWeight { index: 7, .. })));
+    }
+
+    #[test]
+    fn gen_random_weighted_single_test() {
+        for index in [0, 42, 100] {
+            let mut weights = [0.0; 101];
+            weights[index] = 3.5;
+
+            for _ in 0..50 {
+                assert_eq!(gen_random_weighted(&weights), Ok(Guess::new(index as i32).unwrap()));
+            }
+        }
+    }
+
+    #[test]
+    fn gen_random_weighted_uniform_test() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let mut counts = [0; 101];
+
+        for _ in 0..101_000 {
+            counts[gen_random_weighted_with_rng(&mut rng, &[2.0; 101]).unwrap().get() as usize] += 1;
+        }
+
+        assert!(counts.iter().all(|&count| (700..=1300).contains(&count)));
+    }
+
+    #[cfg(feature = "gaussian")]
+    #[test]
+    fn gen_random_gaussian_fixed_test() {
+        for _ in 0..100 {
+            assert_eq!(gen_random_gaussian(42.3, 0.0), Guess::new(42).unwrap());
+            assert_eq!(gen_random_gaussian(42.5, 0.0), Guess::new(43).unwrap());
+            assert_eq!(gen_random_gaussian(-20.0, 0.0), Guess::MIN);
+            assert_eq!(gen_random_gaussian(250.0, 0.0), Guess::MAX);
+        }
+    }
+
+    #[cfg(feature = "gaussian")]
+    #[test]
+    fn gen_random_gaussian_mean_test() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let n = 10_000;
+
+        let sum: i32 = (0..n).map(|_| gen_random_gaussian_with_rng(&mut rng, 50.0, 10.0).get()).sum();
+        let mean = sum as f64 / n as f64;
+
+        // The standard error of the mean is 10 / sqrt(10000) = 0.1, this allows five of them.
+        assert!((mean - 50.0).abs() < 0.5);
+    }
+
+    #[cfg(feature = "gaussian")]
+    #[test]
+    #[should_panic]
+    fn gen_random_gaussian_invalid_std_dev_test() {
+        gen_random_gaussian(50.0, f64::NAN);
+    }
+}
@@ -0,0 +1,625 @@
+//! Inclusive subranges of the valid guess values.
+
+use crate::err::GuessError;
+use crate::hint::GuessHint;
+use crate::{Guess, GUESS_RANGE_MAX, GUESS_RANGE_MIN};
+use rand::Rng;
+
+use alloc::format;
+use alloc::vec::Vec;
+
+/// An inclusive range of valid guess values, from `min` to `max`.
+/// Useful for constrained game modes and for tracking the candidates left in a binary search.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessRange};
+/// 
+/// let range = GuessRange::new(20, 30).unwrap();
+/// 
+/// assert_eq!(range.len(), 11);
+/// assert!(range.contains_guess(&Guess::new(25).unwrap()));
+/// assert_eq!(range.to_string(), "[20, 30]");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GuessRange {
+    min: i32,
+    max: i32,
+}
+
+impl GuessRange {
+    /// Creates a new range from `min` to `max`, both inclusive.
+    /// A `RangeError` will be returned if either of the bounds is outside (0..101) range,
+    /// or an `InvertedRange` error if `max` is smaller than `min`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// use guessing_utils::err::GuessError;
+    /// 
+    /// assert!(GuessRange::new(10, 20).is_ok());
+    /// assert_eq!(GuessRange::new(20, 10), Err(GuessError::InvertedRange { min: 20, max: 10 }));
+    /// ```
+    pub fn new(min: i32, max: i32) -> Result<GuessRange, GuessError> {
+        Guess::new(min)?;
+        Guess::new(max)?;
+
+        if min > max {
+            return Err(GuessError::InvertedRange { min, max });
+        }
+
+        Ok(GuessRange { min, max })
+    }
+
+    /// Creates a new range covering every valid value, from `0` to `100`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// assert_eq!(GuessRange::from_full_range().len(), 101);
+    /// ```
+    pub fn from_full_range() -> GuessRange {
+        GuessRange { min: GUESS_RANGE_MIN, max: GUESS_RANGE_MAX }
+    }
+
+    /// Creates a new range between two guesses, both inclusive.
+    /// An `InvertedRange` error will be returned if `hi` is smaller than `lo`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange};
+    /// 
+    /// let (lo, hi) = (Guess::new(10).unwrap(), Guess::new(20).unwrap());
+    /// 
+    /// assert_eq!(GuessRange::from_guess_pair(&lo, &hi), GuessRange::new(10, 20));
+    /// assert!(GuessRange::from_guess_pair(&hi, &lo).is_err());
+    /// ```
+    pub fn from_guess_pair(lo: &Guess, hi: &Guess) -> Result<GuessRange, GuessError> {
+        GuessRange::new(lo.val, hi.val)
+    }
+
+    /// Creates a new range between two guesses in any order, putting the smaller one first.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange};
+    /// 
+    /// let (a, b) = (Guess::new(20).unwrap(), Guess::new(10).unwrap());
+    /// 
+    /// assert_eq!(GuessRange::from_ordered_pair(&a, &b), GuessRange::new(10, 20).unwrap());
+    /// ```
+    pub fn from_ordered_pair(a: &Guess, b: &Guess) -> GuessRange {
+        GuessRange { min: a.val.min(b.val), max: a.val.max(b.val) }
+    }
+
+    /// Creates a new range holding only the provided guess.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange};
+    /// 
+    /// let range = GuessRange::singleton(&Guess::new(42).unwrap());
+    /// 
+    /// assert_eq!(range.len(), 1);
+    /// assert!(range.is_singleton());
+    /// ```
+    pub fn singleton(g: &Guess) -> GuessRange {
+        GuessRange { min: g.val, max: g.val }
+    }
+
+    /// Gets the smallest value in the range.
+    pub fn min(&self) -> Guess {
+        Guess { val: self.min }
+    }
+
+    /// Gets the largest value in the range.
+    pub fn max(&self) -> Guess {
+        Guess { val: self.max }
+    }
+
+    /// Checks if the value is inside the range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let range = GuessRange::new(10, 20).unwrap();
+    /// 
+    /// assert!(range.contains_value(10));
+    /// assert!(!range.contains_value(21));
+    /// ```
+    pub fn contains_value(&self, v: i32) -> bool {
+        (self.min..=self.max).contains(&v)
+    }
+
+    /// Checks if the guess is inside the range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange};
+    /// 
+    /// let range = GuessRange::new(10, 20).unwrap();
+    /// 
+    /// assert!(range.contains_guess(&Guess::new(15).unwrap()));
+    /// ```
+    pub fn contains_guess(&self, g: &Guess) -> bool {
+        self.contains_value(g.val)
+    }
+
+    /// Generates a random guess inside the range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let range = GuessRange::new(10, 20).unwrap();
+    /// 
+    /// assert!(range.contains_guess(&range.random()));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn random(&self) -> Guess {
+        self.random_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Generates a random guess inside the range using the provided random number generator.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// 
+    /// let range = GuessRange::new(10, 20).unwrap();
+    /// 
+    /// assert!(range.contains_guess(&range.random_with_rng(&mut StdRng::seed_from_u64(42))));
+    /// ```
+    pub fn random_with_rng<R: Rng>(&self, rng: &mut R) -> Guess {
+        Guess { val: rng.gen_range(self.min..=self.max) }
+    }
+
+    /// Narrows the range by the hint given for `pivot`: `TooHigh` lowers the upper bound below the pivot,
+    /// `TooLow` raises the lower bound above it and `Correct` leaves the pivot alone.
+    /// The range never becomes empty, a hint contradicting the range leaves the closest bound alone.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange};
+    /// use guessing_utils::hint::GuessHint;
+    /// 
+    /// let range = GuessRange::from_full_range().narrow(&GuessHint::TooLow, &Guess::MIDPOINT);
+    /// 
+    /// assert_eq!(range, GuessRange::new(51, 100).unwrap());
+    /// assert_eq!(range.narrow(&GuessHint::TooHigh, &Guess::new(75).unwrap()), GuessRange::new(51, 74).unwrap());
+    /// ```
+    pub fn narrow(&self, hint: &GuessHint, pivot: &Guess) -> GuessRange {
+        match hint {
+            GuessHint::Correct => GuessRange::singleton(pivot),
+            GuessHint::TooHigh => GuessRange { max: (pivot.val - 1).clamp(self.min, self.max), ..*self },
+            GuessHint::TooLow => GuessRange { min: (pivot.val + 1).clamp(self.min, self.max), ..*self },
+        }
+    }
+
+    /// Generates `count` distinct random guesses inside the range, in random order.
+    /// An error will be returned if `count` is greater than the length of the range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let range = GuessRange::new(10, 20).unwrap();
+    /// let sequence = range.random_unique_sequence(5).unwrap();
+    /// 
+    /// assert_eq!(sequence.len(), 5);
+    /// assert!(sequence.iter().all(|g| range.contains_guess(g)));
+    /// assert!(range.random_unique_sequence(12).is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn random_unique_sequence(&self, count: usize) -> Result<Vec<Guess>, GuessError> {
+        self.random_unique_sequence_with_rng(&mut rand::thread_rng(), count)
+    }
+
+    /// Generates `count` distinct random guesses inside the range, in random order,
+    /// using the provided random number generator.
+    /// An error will be returned if `count` is greater than the length of the range.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// 
+    /// let range = GuessRange::new(10, 20).unwrap();
+    /// let sequence = range.random_unique_sequence_with_rng(&mut StdRng::seed_from_u64(42), 11).unwrap();
+    /// 
+    /// assert_eq!(sequence.len(), 11);
+    /// ```
+    pub fn random_unique_sequence_with_rng<R: Rng>(&self, rng: &mut R, count: usize) -> Result<Vec<Guess>, GuessError> {
+        let available = self.len() as usize;
+        if count > available {
+            return Err(GuessError::InsufficientValues { requested: count, available });
+        }
+
+        let mut values: Vec<i32> = (self.min..=self.max).collect();
+
+        // A partial Fisher-Yates shuffle, only the first `count` positions need to be random.
+        for i in 0..count {
+            let j = rng.gen_range(i..available);
+            values.swap(i, j);
+        }
+
+        Ok(values[..count].iter().map(|&val| Guess { val }).collect())
+    }
+
+    /// Counts the values in the range, which is never zero.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// assert_eq!(GuessRange::new(10, 20).unwrap().len(), 11);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u32 {
+        (self.max - self.min + 1) as u32
+    }
+
+    /// Gets the value in the middle of the range, rounding down.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange};
+    /// 
+    /// assert_eq!(GuessRange::new(10, 15).unwrap().midpoint(), Guess::new(12).unwrap());
+    /// ```
+    pub fn midpoint(&self) -> Guess {
+        Guess::midpoint(&self.min(), &self.max())
+    }
+
+    /// Checks if the range holds a single value.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// assert!(GuessRange::new(42, 42).unwrap().is_singleton());
+    /// ```
+    pub fn is_singleton(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Checks if the range covers every valid value.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// assert!(GuessRange::new(0, 100).unwrap().is_full_range());
+    /// ```
+    pub fn is_full_range(&self) -> bool {
+        self.min == GUESS_RANGE_MIN && self.max == GUESS_RANGE_MAX
+    }
+
+    /// Iterates over the range starting at its smallest value, advancing by `step` each time.
+    /// # Panics
+    /// Panics if `step` is `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let tens: Vec<i32> = GuessRange::from_full_range().step_by(10).map(|g| g.get()).collect();
+    /// 
+    /// assert_eq!(tens, [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
+    /// ```
+    pub fn step_by(&self, step: u32) -> impl Iterator<Item = Guess> {
+        assert!(step != 0, "step must be positive");
+
+        self.iter().step_by(step as usize)
+    }
+
+    /// Iterates over every value in the range in descending order.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let values: Vec<i32> = GuessRange::new(3, 6).unwrap().rev_iter().map(|g| g.get()).collect();
+    /// 
+    /// assert_eq!(values, [6, 5, 4, 3]);
+    /// ```
+    pub fn rev_iter(&self) -> impl Iterator<Item = Guess> {
+        self.iter().rev()
+    }
+
+    /// Gets the values shared by both ranges, or `None` if they are disjoint.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let a = GuessRange::new(20, 60).unwrap();
+    /// let b = GuessRange::new(40, 80).unwrap();
+    /// 
+    /// assert_eq!(a.overlap(&b), Some(GuessRange::new(40, 60).unwrap()));
+    /// ```
+    pub fn overlap(&self, other: &GuessRange) -> Option<GuessRange> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+
+        (min <= max).then_some(GuessRange { min, max })
+    }
+
+    /// Gets the smallest range containing both ranges, including any gap between them.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let a = GuessRange::new(10, 20).unwrap();
+    /// let b = GuessRange::new(40, 50).unwrap();
+    /// 
+    /// assert_eq!(a.union(&b), GuessRange::new(10, 50).unwrap());
+    /// ```
+    pub fn union(&self, other: &GuessRange) -> GuessRange {
+        GuessRange { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    /// Splits the range into the values below and above `pivot`, leaving the pivot out.
+    /// A half is `None` when it would be empty.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange};
+    /// 
+    /// let (below, above) = GuessRange::from_full_range().split_at(&Guess::MIDPOINT);
+    /// 
+    /// assert_eq!(below, Some(GuessRange::new(0, 49).unwrap()));
+    /// assert_eq!(above, Some(GuessRange::new(51, 100).unwrap()));
+    /// ```
+    pub fn split_at(&self, pivot: &Guess) -> (Option<GuessRange>, Option<GuessRange>) {
+        let below = (self.min < pivot.val).then(|| GuessRange { min: self.min, max: self.max.min(pivot.val - 1) });
+        let above = (self.max > pivot.val).then(|| GuessRange { min: self.min.max(pivot.val + 1), max: self.max });
+
+        (below, above)
+    }
+
+    /// Gets the ranges of valid values not covered by this range, in ascending order.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessRange;
+    /// 
+    /// let complement = GuessRange::new(30, 70).unwrap().complement();
+    /// 
+    /// assert_eq!(complement, vec![GuessRange::new(0, 29).unwrap(), GuessRange::new(71, 100).unwrap()]);
+    /// ```
+    pub fn complement(&self) -> Vec<GuessRange> {
+        let (below, _) = GuessRange::from_full_range().split_at(&self.min());
+        let (_, above) = GuessRange::from_full_range().split_at(&self.max());
+
+        below.into_iter().chain(above).collect()
+    }
+}
+
+impl core::fmt::Display for GuessRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&format!("[{}, {}]", self.min, self.max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::err::GuessRangeError;
+
+    #[test]
+    fn new_test() {
+        assert!(GuessRange::new(0, 100).is_ok());
+        assert!(GuessRange::new(42, 42).is_ok());
+        assert_eq!(GuessRange::new(-1, 50), Err(GuessError::RangeError(GuessRangeError(-1))));
+        assert_eq!(GuessRange::new(50, 101), Err(GuessError::RangeError(GuessRangeError(101))));
+        assert_eq!(GuessRange::new(60, 50), Err(GuessError::InvertedRange { min: 60, max: 50 }));
+        assert_eq!(GuessRange::new(60, 50).unwrap_err().to_string(), "the range 60-50 is inverted");
+        assert_eq!(GuessRange::new(0, 100), Ok(GuessRange::from_full_range()));
+    }
+
+    #[test]
+    fn contains_test() {
+        let range = GuessRange::new(10, 20).unwrap();
+
+        assert!(range.contains_value(10));
+        assert!(range.contains_value(20));
+        assert!(!range.contains_value(9));
+        assert!(!range.contains_value(21));
+        assert!(!range.contains_value(-15));
+        assert!(range.contains_guess(&Guess::new(15).unwrap()));
+        assert!(!range.contains_guess(&Guess::MAX));
+
+        for _ in 0..100 {
+            assert!(range.contains_guess(&range.random()));
+        }
+
+        let single = GuessRange::new(7, 7).unwrap();
+        assert_eq!(single.random(), Guess::new(7).unwrap());
+    }
+
+    #[test]
+    fn properties_test() {
+        let range = GuessRange::new(10, 15).unwrap();
+        let full = GuessRange::from_full_range();
+
+        assert_eq!(range.len(), 6);
+        assert_eq!(full.len(), 101);
+        assert_eq!(range.min(), Guess::new(10).unwrap());
+        assert_eq!(range.max(), Guess::new(15).unwrap());
+        assert_eq!(range.midpoint(), Guess::new(12).unwrap());
+        assert_eq!(full.midpoint(), Guess::MIDPOINT);
+        assert!(!range.is_singleton());
+        assert!(GuessRange::new(15, 15).unwrap().is_singleton());
+        assert!(!range.is_full_range());
+        assert!(full.is_full_range());
+        assert_eq!(range.to_string(), "[10, 15]");
+        assert_eq!(format!("{:>10}", range), "  [10, 15]");
+    }
+
+    #[test]
+    fn iter_test() {
+        let full = GuessRange::from_full_range();
+        let range = GuessRange::new(3, 6).unwrap();
+        let values = |iter: &mut dyn Iterator<Item = Guess>| iter.map(|g| g.get()).collect::<Vec<_>>();
+
+        assert_eq!(full.iter().count(), 101);
+        assert_eq!(full.iter().next(), Some(Guess::MIN));
+        assert_eq!(full.iter().next_back(), Some(Guess::MAX));
+        assert_eq!(values(&mut range.iter()), [3, 4, 5, 6]);
+        assert_eq!(values(&mut range.rev_iter()), [6, 5, 4, 3]);
+        assert_eq!(values(&mut GuessRange::new(9, 9).unwrap().iter()), [9]);
+    }
+
+    #[test]
+    fn step_by_test() {
+        let full = GuessRange::from_full_range();
+        let values = |step| full.step_by(step).map(|g| g.get()).collect::<Vec<_>>();
+
+        assert_eq!(values(10), [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 100]);
+        assert_eq!(values(30), [0, 30, 60, 90]);
+        assert_eq!(values(1).len(), 101);
+        assert_eq!(values(500), [0]);
+
+        let range = GuessRange::new(5, 17).unwrap();
+        assert_eq!(range.step_by(4).map(|g| g.get()).collect::<Vec<_>>(), [5, 9, 13, 17]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_by_zero_test() {
+        let _ = GuessRange::from_full_range().step_by(0);
+    }
+
+    fn range(min: i32, max: i32) -> GuessRange {
+        GuessRange::new(min, max).unwrap()
+    }
+
+    #[test]
+    fn overlap_test() {
+        assert_eq!(range(20, 60).overlap(&range(40, 80)), Some(range(40, 60)));
+        assert_eq!(range(40, 80).overlap(&range(20, 60)), Some(range(40, 60)));
+        assert_eq!(range(20, 60).overlap(&range(60, 80)), Some(range(60, 60)));
+        assert_eq!(range(20, 60).overlap(&range(30, 40)), Some(range(30, 40)));
+        assert_eq!(range(20, 60).overlap(&range(61, 80)), None);
+    }
+
+    #[test]
+    fn union_test() {
+        assert_eq!(range(20, 60).union(&range(40, 80)), range(20, 80));
+        assert_eq!(range(0, 10).union(&range(90, 100)), GuessRange::from_full_range());
+        assert_eq!(range(20, 60).union(&range(30, 40)), range(20, 60));
+    }
+
+    #[test]
+    fn split_at_test() {
+        let guess = |val| Guess::new(val).unwrap();
+        let full = GuessRange::from_full_range();
+
+        assert_eq!(full.split_at(&guess(50)), (Some(range(0, 49)), Some(range(51, 100))));
+        assert_eq!(full.split_at(&guess(0)), (None, Some(range(1, 100))));
+        assert_eq!(full.split_at(&guess(100)), (Some(range(0, 99)), None));
+        assert_eq!(range(42, 42).split_at(&guess(42)), (None, None));
+        assert_eq!(range(10, 20).split_at(&guess(5)), (None, Some(range(10, 20))));
+        assert_eq!(range(10, 20).split_at(&guess(25)), (Some(range(10, 20)), None));
+    }
+
+    #[test]
+    fn complement_test() {
+        assert_eq!(range(30, 70).complement(), vec![range(0, 29), range(71, 100)]);
+        assert_eq!(range(0, 70).complement(), vec![range(71, 100)]);
+        assert_eq!(range(30, 100).complement(), vec![range(0, 29)]);
+        assert!(GuessRange::from_full_range().complement().is_empty());
+
+        for r in [range(0, 0), range(5, 95), range(100, 100)] {
+            let covered: u32 = r.complement().iter().map(|c| c.len()).sum();
+            assert_eq!(covered + r.len(), 101);
+        }
+    }
+
+    #[test]
+    fn guess_pair_constructors_test() {
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(GuessRange::from_guess_pair(&guess(10), &guess(20)), Ok(range(10, 20)));
+        assert_eq!(GuessRange::from_guess_pair(&guess(7), &guess(7)), Ok(range(7, 7)));
+        assert_eq!(GuessRange::from_guess_pair(&Guess::MIN, &Guess::MAX), Ok(GuessRange::from_full_range()));
+        assert_eq!(GuessRange::from_guess_pair(&guess(20), &guess(10)), Err(GuessError::InvertedRange { min: 20, max: 10 }));
+
+        assert_eq!(GuessRange::from_ordered_pair(&guess(10), &guess(20)), range(10, 20));
+        assert_eq!(GuessRange::from_ordered_pair(&guess(20), &guess(10)), range(10, 20));
+        assert_eq!(GuessRange::from_ordered_pair(&guess(5), &guess(5)), range(5, 5));
+
+        for val in [0, 42, 100] {
+            let single = GuessRange::singleton(&guess(val));
+
+            assert_eq!(single.len(), 1);
+            assert_eq!((single.min(), single.max()), (guess(val), guess(val)));
+            assert_eq!(single.iter().collect::<Vec<_>>(), [guess(val)]);
+        }
+    }
+
+    #[test]
+    fn random_unique_sequence_test() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for r in [range(10, 20), range(7, 7), GuessRange::from_full_range()] {
+            let len = r.len() as usize;
+
+            for count in [0, 1, len / 2, len] {
+                let sequence = r.random_unique_sequence_with_rng(&mut rng, count).unwrap();
+                let mut sorted = sequence.clone();
+                sorted.sort_unstable();
+                sorted.dedup();
+
+                assert_eq!(sequence.len(), count);
+                assert_eq!(sorted.len(), count);
+                assert!(sequence.iter().all(|g| r.contains_guess(g)));
+            }
+
+            let mut permutation = r.random_unique_sequence(len).unwrap();
+            permutation.sort_unstable();
+            assert_eq!(permutation, r.iter().collect::<Vec<_>>());
+
+            assert_eq!(
+                r.random_unique_sequence_with_rng(&mut rng, len + 1),
+                Err(GuessError::InsufficientValues { requested: len + 1, available: len })
+            );
+        }
+    }
+
+    #[test]
+    fn random_with_rng_test() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for r in [range(10, 20), range(7, 7), GuessRange::from_full_range()] {
+            for _ in 0..100 {
+                assert!(r.contains_guess(&r.random_with_rng(&mut rng)));
+            }
+        }
+
+        let (mut a, mut b) = (StdRng::seed_from_u64(7), StdRng::seed_from_u64(7));
+        assert_eq!(range(0, 100).random_with_rng(&mut a), range(0, 100).random_with_rng(&mut b));
+    }
+
+    #[test]
+    fn narrow_test() {
+        let full = GuessRange::from_full_range();
+        let guess = |val| Guess::new(val).unwrap();
+
+        assert_eq!(full.narrow(&GuessHint::TooLow, &guess(50)), range(51, 100));
+        assert_eq!(full.narrow(&GuessHint::TooHigh, &guess(50)), range(0, 49));
+        assert_eq!(full.narrow(&GuessHint::Correct, &guess(50)), range(50, 50));
+        assert_eq!(range(51, 100).narrow(&GuessHint::TooHigh, &guess(51)), range(51, 51));
+        assert_eq!(range(51, 100).narrow(&GuessHint::TooLow, &Guess::MAX), range(100, 100));
+        assert_eq!(range(51, 100).narrow(&GuessHint::TooLow, &guess(10)), range(51, 100));
+
+        for target in (0..=100).map(guess) {
+            let mut r = full;
+            let mut steps = 0;
+
+            while !r.is_singleton() || r.min() != target {
+                let pivot = r.midpoint();
+                let narrowed = r.narrow(&pivot.compare_to_target(&target), &pivot);
+
+                assert!(narrowed.contains_guess(&target));
+                assert!(narrowed.len() <= r.len());
+
+                r = narrowed;
+                steps += 1;
+            }
+
+            assert!(steps <= 7);
+        }
+    }
+}
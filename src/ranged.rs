@@ -0,0 +1,142 @@
+//! Guesses in a custom range, fixed at compile time.
+
+use crate::err::GuessError;
+use crate::{Guess, GUESS_RANGE_MAX, GUESS_RANGE_MIN};
+
+use core::fmt;
+
+/// A guess holding a value in the inclusive range from `MIN` to `MAX`, for games not played on (0..101).
+/// Using a `MIN` greater than `MAX` fails to compile.
+/// # Example
+/// ```
+/// use guessing_utils::RangedGuess;
+/// 
+/// type DieRoll = RangedGuess<1, 6>;
+/// 
+/// assert_eq!(DieRoll::new(4).unwrap().get(), 4);
+/// assert!(DieRoll::new(7).is_err());
+/// ```
+/// 
+/// ```compile_fail
+/// use guessing_utils::RangedGuess;
+/// 
+/// let guess = RangedGuess::<6, 1>::new(3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RangedGuess<const MIN: i32, const MAX: i32> {
+    val: i32,
+}
+
+/// A ranged guess covering the same (0..101) range as `Guess`.
+pub type StandardGuess = RangedGuess<GUESS_RANGE_MIN, GUESS_RANGE_MAX>;
+
+impl<const MIN: i32, const MAX: i32> RangedGuess<MIN, MAX> {
+    /// Evaluated when a ranged guess is created, rejecting an empty range at compile time.
+    const VALID_RANGE: () = assert!(MIN <= MAX, "MIN must not be greater than MAX");
+
+    /// Creates a new ranged guess.
+    /// An `OutOfBounds` error holding the value and the bounds will be returned
+    /// if it is outside the range from `MIN` to `MAX`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::RangedGuess;
+    /// 
+    /// assert_eq!(RangedGuess::<1, 6>::new(7).unwrap_err().to_string(), "7 is outside 1-6");
+    /// ```
+    pub fn new(val: i32) -> Result<Self, GuessError> {
+        let () = Self::VALID_RANGE;
+
+        if (MIN..=MAX).contains(&val) {
+            Ok(RangedGuess { val })
+        } else {
+            Err(GuessError::OutOfBounds { value: val, min: MIN, max: MAX })
+        }
+    }
+
+    /// Gets the stored value.
+    pub fn get(&self) -> i32 {
+        self.val
+    }
+}
+
+impl<const MIN: i32, const MAX: i32> fmt::Display for RangedGuess<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.val, f)
+    }
+}
+
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, StandardGuess};
+/// 
+/// let guess: Guess = StandardGuess::new(42).unwrap().into();
+/// 
+/// assert_eq!(guess, Guess::new(42).unwrap());
+/// ```
+impl From<StandardGuess> for Guess {
+    fn from(guess: StandardGuess) -> Self {
+        Guess { val: guess.val }
+    }
+}
+
+/// An `OutOfBounds` error will be returned if the guess is outside the range from `MIN` to `MAX`.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, RangedGuess};
+/// 
+/// assert!(RangedGuess::<1, 6>::try_from(Guess::new(3).unwrap()).is_ok());
+/// assert!(RangedGuess::<1, 6>::try_from(Guess::new(30).unwrap()).is_err());
+/// ```
+impl<const MIN: i32, const MAX: i32> TryFrom<Guess> for RangedGuess<MIN, MAX> {
+    type Error = GuessError;
+
+    fn try_from(guess: Guess) -> Result<Self, Self::Error> {
+        RangedGuess::new(guess.val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranged_guess_new_test() {
+        assert_eq!(RangedGuess::<1, 6>::new(4).map(|g| g.get()), Ok(4));
+        assert_eq!(RangedGuess::<1, 6>::new(1).map(|g| g.get()), Ok(1));
+        assert_eq!(RangedGuess::<1, 6>::new(6).map(|g| g.get()), Ok(6));
+        assert_eq!(RangedGuess::<1, 6>::new(7), Err(GuessError::OutOfBounds { value: 7, min: 1, max: 6 }));
+        assert_eq!(RangedGuess::<1, 6>::new(0), Err(GuessError::OutOfBounds { value: 0, min: 1, max: 6 }));
+        assert_eq!(RangedGuess::<1, 6>::new(7).unwrap_err().to_string(), "7 is outside 1-6");
+        assert_eq!(RangedGuess::<-10, -5>::new(0).unwrap_err().to_string(), "0 is outside -10--5");
+
+        assert!(RangedGuess::<-10, -5>::new(-7).is_ok());
+        assert!(RangedGuess::<3, 3>::new(3).is_ok());
+        assert!(RangedGuess::<3, 3>::new(4).is_err());
+        assert_eq!(RangedGuess::<1, 6>::new(4).unwrap().to_string(), "4");
+    }
+
+    #[test]
+    fn standard_guess_round_trip_test() {
+        for val in -5..=105 {
+            let standard = StandardGuess::new(val);
+
+            assert_eq!(standard.is_ok(), Guess::new(val).is_ok());
+
+            if let Ok(standard) = standard {
+                let guess = Guess::from(standard);
+
+                assert_eq!(guess.get(), val);
+                assert_eq!(StandardGuess::try_from(guess), Ok(standard));
+            }
+        }
+    }
+
+    #[test]
+    fn try_from_guess_test() {
+        let guess = Guess::new(6).unwrap();
+
+        assert_eq!(RangedGuess::<1, 6>::try_from(guess).map(|g| g.get()), Ok(6));
+        assert_eq!(RangedGuess::<1, 5>::try_from(guess), Err(GuessError::OutOfBounds { value: 6, min: 1, max: 5 }));
+        assert_eq!(RangedGuess::<10, 200>::try_from(Guess::MAX).map(|g| g.get()), Ok(100));
+    }
+}
@@ -0,0 +1,104 @@
+//! Feedback produced by a guessing session.
+
+use alloc::format;
+use core::fmt;
+
+/// The outcome of an attempt made in a `GuessSession`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuessResult {
+    /// The guess is exactly the secret, the game is won.
+    Correct,
+    /// The guess is larger than the secret by `distance`.
+    TooHigh { distance: u32 },
+    /// The guess is smaller than the secret by `distance`.
+    TooLow { distance: u32 },
+    /// The value was already guessed in the session, so the attempt was not counted.
+    AlreadyGuessed,
+    /// No attempts are left, the game is over.
+    GameOver,
+}
+
+impl GuessResult {
+    /// Checks if the result ends the game, either by winning or by running out of attempts.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessResult;
+    /// 
+    /// assert!(GuessResult::Correct.is_terminal());
+    /// assert!(GuessResult::GameOver.is_terminal());
+    /// assert!(!GuessResult::TooLow { distance: 3 }.is_terminal());
+    /// ```
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, GuessResult::Correct | GuessResult::GameOver)
+    }
+
+    /// Checks if the guess was correct.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessResult;
+    /// 
+    /// assert!(GuessResult::Correct.is_correct());
+    /// assert!(!GuessResult::GameOver.is_correct());
+    /// ```
+    pub fn is_correct(&self) -> bool {
+        *self == GuessResult::Correct
+    }
+
+    /// Gets the distance from the secret for `TooHigh` and `TooLow` results.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessResult;
+    /// 
+    /// assert_eq!(GuessResult::TooHigh { distance: 8 }.distance(), Some(8));
+    /// assert_eq!(GuessResult::Correct.distance(), None);
+    /// ```
+    pub fn distance(&self) -> Option<u32> {
+        match self {
+            GuessResult::TooHigh { distance } | GuessResult::TooLow { distance } => Some(*distance),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for GuessResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessResult::Correct => f.pad("Correct! You guessed the number."),
+            GuessResult::TooHigh { distance } => f.pad(&format!("Too high by {}.", distance)),
+            GuessResult::TooLow { distance } => f.pad(&format!("Too low by {}.", distance)),
+            GuessResult::AlreadyGuessed => f.pad("You already guessed that number."),
+            GuessResult::GameOver => f.pad("Game over! No attempts left."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicates_test() {
+        let cases = [
+            (GuessResult::Correct, true, true, None),
+            (GuessResult::TooHigh { distance: 8 }, false, false, Some(8)),
+            (GuessResult::TooLow { distance: 1 }, false, false, Some(1)),
+            (GuessResult::AlreadyGuessed, false, false, None),
+            (GuessResult::GameOver, true, false, None),
+        ];
+
+        for (result, terminal, correct, distance) in cases {
+            assert_eq!(result.is_terminal(), terminal);
+            assert_eq!(result.is_correct(), correct);
+            assert_eq!(result.distance(), distance);
+        }
+    }
+
+    #[test]
+    fn display_test() {
+        assert_eq!(GuessResult::Correct.to_string(), "Correct! You guessed the number.");
+        assert_eq!(GuessResult::TooHigh { distance: 20 }.to_string(), "Too high by 20.");
+        assert_eq!(GuessResult::TooLow { distance: 5 }.to_string(), "Too low by 5.");
+        assert_eq!(GuessResult::AlreadyGuessed.to_string(), "You already guessed that number.");
+        assert_eq!(GuessResult::GameOver.to_string(), "Game over! No attempts left.");
+    }
+}
@@ -0,0 +1,92 @@
+//! Built-in formulas for scoring finished sessions with `GuessSession::score_by_formula`.
+//! 
+//! A formula receives the number of attempts used and the distance of the final guess from the secret,
+//! which is `None` when the game was lost. Every built-in formula scores from `0.0` to `100.0`.
+//! 
+//! # Example
+//! ```
+//! use guessing_utils::{Guess, GuessSession};
+//! use guessing_utils::scoring;
+//! 
+//! let secret = Guess::new(42).unwrap();
+//! let mut session = GuessSession::new(secret, 10);
+//! session.attempt(Guess::new(50).unwrap());
+//! session.attempt(secret);
+//! 
+//! assert_eq!(session.score_by_formula(&scoring::linear_score(10)), Some(90.0));
+//! ```
+
+/// Scores a win linearly by the attempts used, from `100.0` for a first-guess win
+/// down to `100.0 / max_attempts` for a win on the last attempt. A loss scores `0.0`.
+/// # Example
+/// ```
+/// use guessing_utils::scoring::linear_score;
+/// 
+/// let score = linear_score(10);
+/// 
+/// assert_eq!(score(1, Some(0)), 100.0);
+/// assert_eq!(score(10, Some(0)), 10.0);
+/// assert_eq!(score(10, None), 0.0);
+/// ```
+pub fn linear_score(max_attempts: u32) -> impl Fn(u32, Option<u32>) -> f64 {
+    let max_attempts = max_attempts.max(1);
+
+    move |attempts_used, distance| match distance {
+        Some(_) => max_attempts.saturating_sub(attempts_used.saturating_sub(1)) as f64 / max_attempts as f64 * 100.0,
+        None => 0.0,
+    }
+}
+
+/// Scores like `linear_score`, then takes one point off for every unit of distance of the final guess.
+/// The score never drops below `0.0`.
+/// # Example
+/// ```
+/// use guessing_utils::scoring::distance_penalty_score;
+/// 
+/// let score = distance_penalty_score(10);
+/// 
+/// assert_eq!(score(1, Some(0)), 100.0);
+/// assert_eq!(score(1, Some(30)), 70.0);
+/// assert_eq!(score(5, None), 0.0);
+/// ```
+pub fn distance_penalty_score(max_attempts: u32) -> impl Fn(u32, Option<u32>) -> f64 {
+    let linear = linear_score(max_attempts);
+
+    move |attempts_used, distance| match distance {
+        Some(d) => (linear(attempts_used, distance) - d as f64).max(0.0),
+        None => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_score_test() {
+        let score = linear_score(5);
+
+        assert_eq!(score(1, Some(0)), 100.0);
+        assert_eq!(score(2, Some(0)), 80.0);
+        assert_eq!(score(5, Some(0)), 20.0);
+        assert_eq!(score(5, None), 0.0);
+        assert_eq!(score(1, None), 0.0);
+
+        for attempts in 1..5 {
+            assert!(score(attempts, Some(0)) > score(attempts + 1, Some(0)));
+        }
+
+        assert_eq!(linear_score(0)(1, Some(0)), 100.0);
+    }
+
+    #[test]
+    fn distance_penalty_score_test() {
+        let score = distance_penalty_score(5);
+
+        assert_eq!(score(1, Some(0)), 100.0);
+        assert_eq!(score(2, Some(5)), 75.0);
+        assert_eq!(score(5, Some(50)), 0.0);
+        assert_eq!(score(3, None), 0.0);
+        assert!(score(2, Some(1)) < score(2, Some(0)));
+    }
+}
@@ -0,0 +1,2614 @@
+//! Game sessions tracking the secret, the attempts and the history of guesses.
+
+use crate::err::{DeserializeError, GuessError};
+use crate::{Guess, GuessRange, GuessResult, GuessStats, GUESS_RANGE_MAX, GUESS_RANGE_MIN, GUESS_RANGE_SIZE};
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use rand::Rng;
+
+/// The kind of hint a session gives about the most recent guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HintMode {
+    /// No hints at all.
+    None,
+    /// Whether the guess was too high or too low.
+    HighLow,
+    /// How close the guess was, from freezing to burning.
+    Temperature,
+}
+
+impl HintMode {
+    /// Gets the name of the mode, as written by `GuessSession::export_json()`.
+    fn name(&self) -> &'static str {
+        match self {
+            HintMode::None => "None",
+            HintMode::HighLow => "HighLow",
+            HintMode::Temperature => "Temperature",
+        }
+    }
+
+    /// Gets the mode with the provided name, the inverse of `name()`.
+    fn from_name(name: &str) -> Option<HintMode> {
+        [HintMode::None, HintMode::HighLow, HintMode::Temperature].into_iter().find(|mode| mode.name() == name)
+    }
+}
+
+/// Options for the behavior of a `GuessSession`, set with builder methods.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessResult, GuessSession, GuessSessionConfig, HintMode};
+/// 
+/// let config = GuessSessionConfig::default().max_attempts(5).no_repeats().hint_mode(HintMode::Temperature);
+/// let mut session = GuessSession::new_with_config(Guess::new(42).unwrap(), config);
+/// 
+/// assert_eq!(session.attempt(Guess::new(45).unwrap()), GuessResult::TooHigh { distance: 3 });
+/// assert_eq!(session.attempt(Guess::new(45).unwrap()), GuessResult::AlreadyGuessed);
+/// assert_eq!(session.attempts_used(), 1);
+/// assert_eq!(session.hint(), Some("hot".to_string()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuessSessionConfig {
+    /// The number of attempts allowed, `10` by default.
+    pub max_attempts: u32,
+    /// Whether a value can be guessed more than once, `true` by default.
+    /// Otherwise repeated guesses return `AlreadyGuessed` without using up an attempt.
+    pub allow_repeats: bool,
+    /// Whether `distance_history()` is available, `true` by default.
+    pub track_distances: bool,
+    /// The kind of hint given by `hint()`, `HighLow` by default.
+    pub hint_mode: HintMode,
+}
+
+impl GuessSessionConfig {
+    /// Sets the number of attempts allowed.
+    pub fn max_attempts(mut self, n: u32) -> Self {
+        self.max_attempts = n;
+        self
+    }
+
+    /// Rejects values which were already guessed.
+    pub fn no_repeats(mut self) -> Self {
+        self.allow_repeats = false;
+        self
+    }
+
+    /// Sets whether the distances of the guesses from the secret are tracked.
+    pub fn track_distances(mut self, track: bool) -> Self {
+        self.track_distances = track;
+        self
+    }
+
+    /// Sets the kind of hint given about the most recent guess.
+    pub fn hint_mode(mut self, mode: HintMode) -> Self {
+        self.hint_mode = mode;
+        self
+    }
+
+    /// Packs everything but `max_attempts` into the trailing byte of `GuessSession::serialize_to_bytes()`:
+    /// bit 0 allows repeats, bit 1 tracks distances and bits 2 and 3 hold the hint mode.
+    fn to_flags(self) -> u8 {
+        let hint_mode = match self.hint_mode {
+            HintMode::None => 0,
+            HintMode::HighLow => 1,
+            HintMode::Temperature => 2,
+        };
+
+        self.allow_repeats as u8 | (self.track_distances as u8) << 1 | hint_mode << 2
+    }
+
+    /// Unpacks a config from `max_attempts` and a byte written by `to_flags()`.
+    fn from_flags(max_attempts: u32, flags: u8) -> Option<GuessSessionConfig> {
+        let hint_mode = match flags >> 2 {
+            0 => HintMode::None,
+            1 => HintMode::HighLow,
+            2 => HintMode::Temperature,
+            _ => return None,
+        };
+
+        Some(GuessSessionConfig {
+            max_attempts,
+            allow_repeats: flags & 1 != 0,
+            track_distances: flags & 2 != 0,
+            hint_mode,
+        })
+    }
+}
+
+impl Default for GuessSessionConfig {
+    fn default() -> Self {
+        GuessSessionConfig { max_attempts: 10, allow_repeats: true, track_distances: true, hint_mode: HintMode::HighLow }
+    }
+}
+
+/// The number of bytes before the history in `GuessSession::serialize_to_bytes()`.
+const HEADER_LEN: usize = 11;
+
+/// A single game of guessing a secret number in a limited number of attempts.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessResult, GuessSession};
+/// 
+/// let mut session = GuessSession::new(Guess::new(42).unwrap(), 10);
+/// 
+/// let mut lo = 0;
+/// let mut hi = 100;
+/// loop {
+///     let guess = Guess::new((lo + hi) / 2).unwrap();
+/// 
+///     match session.attempt(guess) {
+///         GuessResult::Correct => break,
+///         GuessResult::TooHigh { .. } => hi = guess.get() - 1,
+///         GuessResult::TooLow { .. } => lo = guess.get() + 1,
+///         GuessResult::AlreadyGuessed => continue,
+///         GuessResult::GameOver => panic!("out of attempts"),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "SessionData", try_from = "SessionData")
+)]
+pub struct GuessSession {
+    secret: Guess,
+    config: GuessSessionConfig,
+    attempts_used: u32,
+    history: Vec<Guess>,
+    won: bool,
+    rounds: u32,
+    lo_bound: i32,
+    hi_bound: i32,
+}
+
+impl GuessSession {
+    /// Creates a new session guessing `secret` in at most `max_attempts` attempts.
+    pub fn new(secret: Guess, max_attempts: u32) -> GuessSession {
+        GuessSession::new_with_config(secret, GuessSessionConfig::default().max_attempts(max_attempts))
+    }
+
+    /// Creates a new session guessing `secret`, behaving as set in the config.
+    pub fn new_with_config(secret: Guess, config: GuessSessionConfig) -> GuessSession {
+        GuessSession {
+            secret,
+            config,
+            attempts_used: 0,
+            history: Vec::new(),
+            won: false,
+            rounds: 0,
+            lo_bound: GUESS_RANGE_MIN,
+            hi_bound: GUESS_RANGE_MAX,
+        }
+    }
+
+    /// Creates a new session guessing `secret`, behaving as set in the config.
+    /// This is the same as `new_with_config`, naming both parts explicitly.
+    pub fn new_with_secret_and_config(secret: Guess, config: GuessSessionConfig) -> GuessSession {
+        GuessSession::new_with_config(secret, config)
+    }
+
+    /// Creates a new session with a secret generated by the provided random number generator.
+    /// A seeded generator gives a fully reproducible session.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessSession;
+    /// 
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// 
+    /// let a = GuessSession::new_with_rng(&mut StdRng::seed_from_u64(42), 5);
+    /// let b = GuessSession::new_with_rng(&mut StdRng::seed_from_u64(42), 5);
+    /// 
+    /// assert_eq!(a.secret(), b.secret());
+    /// ```
+    pub fn new_with_rng<R: Rng>(rng: &mut R, max_attempts: u32) -> GuessSession {
+        GuessSession::new(crate::gen_random_with_rng(rng), max_attempts)
+    }
+
+    /// Creates a new session with a randomly generated secret.
+    #[cfg(feature = "std")]
+    pub fn new_random(max_attempts: u32) -> GuessSession {
+        GuessSession::new(crate::gen_random(), max_attempts)
+    }
+
+    /// Gets the sequence of guesses a perfect binary search makes to find `secret`,
+    /// always guessing the midpoint of the range the secret can still be in, rounding down.
+    /// The last guess of the sequence is the secret.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let sequence: Vec<i32> = GuessSession::optimal_guess_sequence(&Guess::new(30).unwrap())
+    ///     .iter()
+    ///     .map(|g| g.get())
+    ///     .collect();
+    /// 
+    /// assert_eq!(sequence, [50, 24, 37, 30]);
+    /// ```
+    pub fn optimal_guess_sequence(secret: &Guess) -> Vec<Guess> {
+        let (mut lo, mut hi) = (GUESS_RANGE_MIN, GUESS_RANGE_MAX);
+        let mut sequence = Vec::new();
+
+        loop {
+            let guess = Guess { val: lo + (hi - lo) / 2 };
+            sequence.push(guess);
+
+            match guess.cmp(secret) {
+                Ordering::Equal => return sequence,
+                Ordering::Greater => hi = guess.val - 1,
+                Ordering::Less => lo = guess.val + 1,
+            }
+        }
+    }
+
+    /// Counts the guesses a perfect binary search needs to find `secret`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// assert_eq!(GuessSession::optimal_guesses_count(&Guess::MIDPOINT), 1);
+    /// assert_eq!(GuessSession::optimal_guesses_count(&Guess::new(30).unwrap()), 4);
+    /// ```
+    pub fn optimal_guesses_count(secret: &Guess) -> usize {
+        GuessSession::optimal_guess_sequence(secret).len()
+    }
+
+    /// Gets the number of attempts a perfect binary search needs to find any secret, `7`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessSession;
+    /// 
+    /// assert_eq!(GuessSession::minimum_guaranteed_attempts(), 7);
+    /// ```
+    pub fn minimum_guaranteed_attempts() -> u32 {
+        // A binary search splitting `n` values takes at most `floor(log2(n)) + 1` guesses.
+        u32::BITS - (GUESS_RANGE_SIZE as u32).leading_zeros()
+    }
+
+    /// Gets the length of the longest sequence returned by `optimal_guess_sequence()`,
+    /// the same as `minimum_guaranteed_attempts()`.
+    pub fn worst_case_sequence_length() -> u32 {
+        GuessSession::minimum_guaranteed_attempts()
+    }
+
+    /// Checks if every secret can be found in at most `max_attempts` attempts with a perfect binary search.
+    /// Useful for choosing attempt limits when designing difficulty levels.
+    /// # Example
+    /// ```
+    /// use guessing_utils::GuessSession;
+    /// 
+    /// assert!(GuessSession::is_solvable_in_n_attempts(7));
+    /// assert!(!GuessSession::is_solvable_in_n_attempts(6));
+    /// ```
+    pub fn is_solvable_in_n_attempts(max_attempts: u32) -> bool {
+        max_attempts >= GuessSession::minimum_guaranteed_attempts()
+    }
+
+    /// Records an attempt and tells how the guess compares to the secret.
+    /// Returns `GameOver` when this attempt uses up the last one without guessing the secret.
+    /// Once the game is over, further attempts are not recorded and also return `GameOver`.
+    /// When repeats are not allowed, guessing a value again returns `AlreadyGuessed` without being recorded.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessResult, GuessSession};
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let mut session = GuessSession::new(secret, 2);
+    /// 
+    /// assert_eq!(session.attempt(Guess::new(50).unwrap()), GuessResult::TooHigh { distance: 8 });
+    /// assert_eq!(session.attempt(secret), GuessResult::Correct);
+    /// assert_eq!(session.attempt(secret), GuessResult::GameOver);
+    /// ```
+    pub fn attempt(&mut self, guess: Guess) -> GuessResult {
+        if self.is_over() {
+            return GuessResult::GameOver;
+        }
+        if !self.config.allow_repeats && self.history.contains(&guess) {
+            return GuessResult::AlreadyGuessed;
+        }
+
+        self.history.push(guess);
+        self.attempts_used += 1;
+
+        let result = self.evaluate(&guess, self.attempts_used);
+        if result == GuessResult::Correct {
+            self.won = true;
+        }
+        self.narrow(&guess, &result);
+
+        result
+    }
+
+    /// Gets the secret the session is about.
+    pub fn secret(&self) -> Guess {
+        self.secret
+    }
+
+    /// Gets the number of attempts allowed in the session.
+    pub fn max_attempts(&self) -> u32 {
+        self.config.max_attempts
+    }
+
+    /// Gets the config the session behaves by.
+    pub fn config(&self) -> &GuessSessionConfig {
+        &self.config
+    }
+
+    /// Gives a hint about the most recent guess in the kind set by the config.
+    /// Returns `None` before the first guess or when hints are disabled.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// assert_eq!(session.hint(), None);
+    /// 
+    /// session.attempt(Guess::new(50).unwrap());
+    /// assert_eq!(session.hint(), Some("Too high! Try a lower number.".to_string()));
+    /// ```
+    pub fn hint(&self) -> Option<String> {
+        let guess = self.history.last()?;
+
+        match self.config.hint_mode {
+            HintMode::None => None,
+            HintMode::HighLow => Some(guess.compare_to_target(&self.secret).to_string()),
+            HintMode::Temperature => Some(guess.temperature_hint(&self.secret).to_string()),
+        }
+    }
+
+    /// Gets the distance of every guess from the secret, in the order they were made.
+    /// Returns `None` when the config does not track distances.
+    pub fn distance_history(&self) -> Option<Vec<u32>> {
+        self.config.track_distances.then(|| self.errors().collect())
+    }
+
+    /// Checks if the secret was guessed.
+    pub fn is_won(&self) -> bool {
+        self.won
+    }
+
+    /// Checks if all attempts were used up without guessing the secret.
+    pub fn is_lost(&self) -> bool {
+        !self.won && self.attempts_used >= self.config.max_attempts
+    }
+
+    /// Checks if the game is over, either won or lost.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 1);
+    /// assert!(!session.is_over());
+    /// 
+    /// session.attempt(Guess::MIN);
+    /// assert!(session.is_over());
+    /// assert!(session.is_lost());
+    /// ```
+    pub fn is_over(&self) -> bool {
+        self.is_won() || self.is_lost()
+    }
+
+    /// Gets the number of attempts left.
+    /// A session created with `u32::MAX` attempts is effectively unlimited,
+    /// the count will not noticeably decrease in any real game.
+    pub fn attempts_remaining(&self) -> u32 {
+        self.config.max_attempts - self.attempts_used
+    }
+
+    /// Gets the number of attempts made so far.
+    pub fn attempts_used(&self) -> u32 {
+        self.attempts_used
+    }
+
+    /// Gets every guess made so far, in the order they were made.
+    pub fn history(&self) -> &[Guess] {
+        &self.history
+    }
+
+    /// Gets the range the secret can still be in, narrowed by every `TooLow` and `TooHigh` hint so far.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessRange, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(60).unwrap(), 10);
+    /// assert_eq!(session.remaining_range(), GuessRange::from_full_range());
+    /// 
+    /// session.attempt(Guess::new(50).unwrap());
+    /// session.attempt(Guess::new(75).unwrap());
+    /// assert_eq!(session.remaining_range(), GuessRange::new(51, 74).unwrap());
+    /// ```
+    pub fn remaining_range(&self) -> GuessRange {
+        GuessRange::new(self.lo_bound, self.hi_bound).expect("the bounds always contain the secret")
+    }
+
+    /// Starts a new round with a randomly generated secret, keeping the attempt limit.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(42).unwrap());
+    /// 
+    /// session.reset();
+    /// 
+    /// assert!(!session.is_won());
+    /// assert!(session.history().is_empty());
+    /// assert_eq!(session.round_count(), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn reset(&mut self) {
+        self.reset_with_secret(crate::gen_random());
+    }
+
+    /// Starts a new round with a secret generated by the provided random number generator, keeping the attempt limit.
+    pub fn reset_with_rng<R: Rng>(&mut self, rng: &mut R) {
+        self.reset_with_secret(crate::gen_random_with_rng(rng));
+    }
+
+    /// Starts a new round with the provided secret, keeping the attempt limit.
+    pub fn reset_with_secret(&mut self, new_secret: Guess) {
+        self.secret = new_secret;
+        self.attempts_used = 0;
+        self.history.clear();
+        self.won = false;
+        self.rounds += 1;
+        self.lo_bound = GUESS_RANGE_MIN;
+        self.hi_bound = GUESS_RANGE_MAX;
+    }
+
+    /// Gets the number of times the session was reset.
+    pub fn round_count(&self) -> u32 {
+        self.rounds
+    }
+
+    /// Gets the index of the first correct guess in the history, counting from `0`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let mut session = GuessSession::new(secret, 5);
+    /// session.attempt(Guess::MIN);
+    /// assert_eq!(session.first_correct_at(), None);
+    /// 
+    /// session.attempt(secret);
+    /// assert_eq!(session.first_correct_at(), Some(1));
+    /// ```
+    pub fn first_correct_at(&self) -> Option<usize> {
+        self.history.iter().position(|g| *g == self.secret)
+    }
+
+    /// Gets the most recent guess.
+    pub fn last_guess(&self) -> Option<&Guess> {
+        self.history.last()
+    }
+
+    /// Gets the `n`-th guess of the history, counting from `0`.
+    pub fn nth_guess(&self, n: usize) -> Option<&Guess> {
+        self.history.get(n)
+    }
+
+    /// Gets the guesses made from the `index`-th one onward, counting from `0`.
+    /// An `index` past the end of the history gives an empty slice.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::MIN);
+    /// session.attempt(Guess::MAX);
+    /// 
+    /// assert_eq!(session.guesses_since(1), [Guess::MAX]);
+    /// assert!(session.guesses_since(5).is_empty());
+    /// ```
+    pub fn guesses_since(&self, index: usize) -> &[Guess] {
+        self.history.get(index..).unwrap_or(&[])
+    }
+
+    /// Gets the guess closest to the secret, preferring the earliest one on ties.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(10).unwrap());
+    /// session.attempt(Guess::new(40).unwrap());
+    /// session.attempt(Guess::new(90).unwrap());
+    /// 
+    /// assert_eq!(session.best_guess(), Some(&Guess::new(40).unwrap()));
+    /// assert_eq!(session.worst_guess(), Some(&Guess::new(90).unwrap()));
+    /// ```
+    pub fn best_guess(&self) -> Option<&Guess> {
+        self.history.iter().min_by_key(|g| g.abs_diff(&self.secret))
+    }
+
+    /// Gets the guess furthest from the secret, preferring the earliest one on ties.
+    pub fn worst_guess(&self) -> Option<&Guess> {
+        self.history.iter().rev().max_by_key(|g| g.abs_diff(&self.secret))
+    }
+
+    /// Gets the `n` guesses closest to the secret, sorted by ascending distance.
+    /// Guesses at the same distance keep the order they were made in.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// for val in [10, 40, 90, 45] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// let best: Vec<i32> = session.best_n_guesses(2).iter().map(|g| g.get()).collect();
+    /// 
+    /// assert_eq!(best, [40, 45]);
+    /// ```
+    pub fn best_n_guesses(&self, n: usize) -> Vec<&Guess> {
+        let mut guesses: Vec<&Guess> = self.history.iter().collect();
+        guesses.sort_by_key(|g| g.abs_diff(&self.secret));
+        guesses.truncate(n);
+        guesses
+    }
+
+    /// Groups the guesses by their distance from the secret, keeping the order they were made in within each group.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(50).unwrap(), 10);
+    /// for val in [60, 40, 55] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// let groups = session.group_by_distance();
+    /// 
+    /// assert_eq!(groups[&10], [Guess::new(60).unwrap(), Guess::new(40).unwrap()]);
+    /// assert_eq!(groups[&5], [Guess::new(55).unwrap()]);
+    /// ```
+    pub fn group_by_distance(&self) -> BTreeMap<u32, Vec<Guess>> {
+        let mut groups: BTreeMap<u32, Vec<Guess>> = BTreeMap::new();
+        for guess in &self.history {
+            groups.entry(guess.abs_diff(&self.secret)).or_default().push(*guess);
+        }
+        groups
+    }
+
+    /// Gets the `n` guesses closest to the secret, the same as `best_n_guesses()`.
+    pub fn closest_cluster(&self, n: usize) -> Vec<&Guess> {
+        self.best_n_guesses(n)
+    }
+
+    /// Gets a copy of the history, sorted in ascending order.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::MAX);
+    /// session.attempt(Guess::MIN);
+    /// 
+    /// assert_eq!(session.sorted_history(), [Guess::MIN, Guess::MAX]);
+    /// ```
+    pub fn sorted_history(&self) -> Vec<Guess> {
+        let mut sorted = self.history.clone();
+        sorted.sort();
+        sorted
+    }
+
+    /// Gets the first occurrence of each guess, in the order they were made.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// for guess in [Guess::MAX, Guess::MIN, Guess::MAX] {
+    ///     session.attempt(guess);
+    /// }
+    /// 
+    /// assert_eq!(session.unique_history(), [Guess::MAX, Guess::MIN]);
+    /// ```
+    pub fn unique_history(&self) -> Vec<Guess> {
+        let mut seen = [false; GUESS_RANGE_SIZE];
+        self.history.iter().filter(|g| !core::mem::replace(&mut seen[g.val as usize], true)).copied().collect()
+    }
+
+    /// Gets the guesses made more than once, each listed a single time in the order they were first made.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// for guess in [Guess::MAX, Guess::MIN, Guess::MAX, Guess::MAX] {
+    ///     session.attempt(guess);
+    /// }
+    /// 
+    /// assert_eq!(session.duplicate_guesses(), [Guess::MAX]);
+    /// ```
+    pub fn duplicate_guesses(&self) -> Vec<Guess> {
+        self.unique_history()
+            .into_iter()
+            .filter(|g| self.history.iter().filter(|h| *h == g).count() > 1)
+            .collect()
+    }
+
+    /// Counts the distinct values in the history.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 10);
+    /// for val in [10, 20, 10, 10] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// assert_eq!(session.unique_guess_count(), 2);
+    /// ```
+    pub fn unique_guess_count(&self) -> usize {
+        self.unique_history().len()
+    }
+
+    /// Calculates the percentage of the 101 valid values guessed so far, counting repeats once.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::MAX, 200);
+    /// for val in 0..=100 {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// assert_eq!(session.coverage_percentage(), 100.0);
+    /// ```
+    pub fn coverage_percentage(&self) -> f64 {
+        self.unique_guess_count() as f64 / GUESS_RANGE_SIZE as f64 * 100.0
+    }
+
+    /// Checks if the guess was made at any point of the history.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::MIN);
+    /// 
+    /// assert!(session.contains(&Guess::MIN));
+    /// assert!(!session.contains(&Guess::MAX));
+    /// ```
+    pub fn contains(&self, guess: &Guess) -> bool {
+        self.history.contains(guess)
+    }
+
+    /// Counts how many times the guess was made.
+    pub fn count_occurrences(&self, guess: &Guess) -> usize {
+        self.history.iter().filter(|g| *g == guess).count()
+    }
+
+    /// Gets the guesses strictly greater than the threshold, in the order they were made.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// for val in [10, 50, 90] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// assert_eq!(session.guesses_above(&Guess::new(50).unwrap()), [&Guess::new(90).unwrap()]);
+    /// assert_eq!(session.guesses_below(&Guess::new(50).unwrap()), [&Guess::new(10).unwrap()]);
+    /// ```
+    pub fn guesses_above(&self, threshold: &Guess) -> Vec<&Guess> {
+        self.history.iter().filter(|g| *g > threshold).collect()
+    }
+
+    /// Gets the guesses strictly less than the threshold, in the order they were made.
+    pub fn guesses_below(&self, threshold: &Guess) -> Vec<&Guess> {
+        self.history.iter().filter(|g| *g < threshold).collect()
+    }
+
+    /// Gets the guesses at most `radius` away from the center, in the order they were made.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// for val in [10, 45, 55, 90] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// assert_eq!(session.guesses_within(&Guess::new(50).unwrap(), 5).len(), 2);
+    /// ```
+    pub fn guesses_within(&self, center: &Guess, radius: u32) -> Vec<&Guess> {
+        self.history.iter().filter(|g| g.abs_diff(center) <= radius).collect()
+    }
+
+    /// Computes statistics over the history, returning `None` when no guesses were made.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// session.attempt(Guess::new(30).unwrap());
+    /// 
+    /// assert_eq!(session.statistics().map(|s| s.mean()), Some(40.0));
+    /// ```
+    pub fn statistics(&self) -> Option<GuessStats> {
+        GuessStats::from_slice(&self.history)
+    }
+
+    /// Computes the mean distance of the guesses from the secret,
+    /// returning `None` when no guesses were made.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// session.attempt(Guess::new(30).unwrap());
+    /// 
+    /// assert_eq!(session.average_distance_from_secret(), Some(10.0));
+    /// ```
+    pub fn average_distance_from_secret(&self) -> Option<f64> {
+        if self.history.is_empty() {
+            return None;
+        }
+
+        let total: u32 = self.history.iter().map(|g| g.abs_diff(&self.secret)).sum();
+        Some(total as f64 / self.history.len() as f64)
+    }
+
+    /// Computes the mean distance of the guesses from the secret,
+    /// the same as `average_distance_from_secret()`.
+    pub fn average_error(&self) -> Option<f64> {
+        self.average_distance_from_secret()
+    }
+
+    /// Gets the largest distance of a guess from the secret.
+    pub fn max_error(&self) -> Option<u32> {
+        self.errors().max()
+    }
+
+    /// Gets the smallest distance of a guess from the secret.
+    pub fn min_error(&self) -> Option<u32> {
+        self.errors().min()
+    }
+
+    /// Computes the slope of the distances from the secret over the attempts, using linear regression.
+    /// A negative slope means the guesses are converging on the secret, a positive one that they are diverging.
+    /// Returns `None` for fewer than two guesses.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(50).unwrap(), 10);
+    /// for val in [10, 30, 40] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// assert_eq!(session.error_improvement(), Some(-15.0));
+    /// ```
+    pub fn error_improvement(&self) -> Option<f64> {
+        if self.history.len() < 2 {
+            return None;
+        }
+
+        let n = self.history.len() as f64;
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = self.average_error()?;
+
+        let (mut covariance, mut variance) = (0.0, 0.0);
+        for (x, y) in self.errors().enumerate() {
+            let dx = x as f64 - mean_x;
+            covariance += dx * (y as f64 - mean_y);
+            variance += dx * dx;
+        }
+
+        Some(covariance / variance)
+    }
+
+    /// Scores a finished session with a custom formula, returning `None` while the game is not over.
+    /// The formula receives the number of attempts used and the distance of the final guess from the secret,
+    /// which is `None` when the game was lost. See the `scoring` module for built-in formulas.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let mut session = GuessSession::new(secret, 5);
+    /// assert_eq!(session.score_by_formula(&|attempts, _| 10.0 - attempts as f64), None);
+    /// 
+    /// session.attempt(secret);
+    /// assert_eq!(session.score_by_formula(&|attempts, _| 10.0 - attempts as f64), Some(9.0));
+    /// ```
+    pub fn score_by_formula(&self, formula: &dyn Fn(u32, Option<u32>) -> f64) -> Option<f64> {
+        if !self.is_over() {
+            return None;
+        }
+
+        let distance = self.won.then(|| self.errors().last().unwrap_or(0));
+
+        Some(formula(self.attempts_used, distance))
+    }
+
+    /// Computes how hard the finished game was, returning `None` while the game is not over.
+    /// The score is the number of attempts used, scaled up by the average error
+    /// as `attempts * (1 + average_error / 100)`, and doubled for a lost game.
+    /// Lower is better, a first-guess win scores `1.0`.
+    /// A game lost without any attempts, when `max_attempts` is `0`, gets the worst possible score.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let mut session = GuessSession::new(secret, 5);
+    /// session.attempt(Guess::new(62).unwrap());
+    /// session.attempt(secret);
+    /// 
+    /// assert_eq!(session.difficulty_score(), Some(2.2));
+    /// ```
+    pub fn difficulty_score(&self) -> Option<f64> {
+        if !self.is_over() {
+            return None;
+        }
+
+        if self.is_lost() && self.attempts_used == 0 {
+            return Some(self.worst_difficulty_score());
+        }
+
+        let average_error = self.average_error().unwrap_or(0.0);
+        let penalty = if self.is_lost() { 2.0 } else { 1.0 };
+
+        Some(self.attempts_used as f64 * (1.0 + average_error / 100.0) * penalty)
+    }
+
+    /// Computes `difficulty_score()` mapped to the range from `0.0` to `1.0`,
+    /// where `0.0` is a first-guess win and `1.0` is the worst possible score:
+    /// a lost game using every attempt, each one `100` away from the secret.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let mut session = GuessSession::new(secret, 5);
+    /// session.attempt(secret);
+    /// 
+    /// assert_eq!(session.normalized_difficulty_score(), Some(0.0));
+    /// ```
+    pub fn normalized_difficulty_score(&self) -> Option<f64> {
+        let score = self.difficulty_score()?;
+        let worst = self.worst_difficulty_score();
+
+        Some(((score - 1.0) / (worst - 1.0)).clamp(0.0, 1.0))
+    }
+
+    fn worst_difficulty_score(&self) -> f64 {
+        self.config.max_attempts.max(1) as f64 * 2.0 * 2.0
+    }
+
+    /// Grades the finished game by `difficulty_score()`, returning `None` while the game is not over.
+    /// The grades go from `"S"` for a score of at most `2.0` through `"A"`, `"B"`, `"C"` and `"D"`,
+    /// while a lost game is always graded `"F"`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let mut session = GuessSession::new(secret, 5);
+    /// session.attempt(secret);
+    /// 
+    /// assert_eq!(session.difficulty_grade(), Some("S"));
+    /// ```
+    pub fn difficulty_grade(&self) -> Option<&'static str> {
+        let score = self.difficulty_score()?;
+
+        let grade = match score {
+            _ if self.is_lost() => "F",
+            s if s <= 2.0 => "S",
+            s if s <= 4.0 => "A",
+            s if s <= 6.0 => "B",
+            s if s <= 9.0 => "C",
+            _ => "D",
+        };
+
+        Some(grade)
+    }
+
+    /// Lazily re-evaluates each guess of the history against the secret, in the order they were made.
+    /// The guess using up the last attempt gives `GameOver` unless it was correct.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessResult, GuessSession};
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// let mut session = GuessSession::new(secret, 2);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// session.attempt(secret);
+    /// 
+    /// let results: Vec<GuessResult> = session.replay().map(|(_, result)| result).collect();
+    /// 
+    /// assert_eq!(results, [GuessResult::TooHigh { distance: 8 }, GuessResult::Correct]);
+    /// ```
+    pub fn replay(&self) -> impl Iterator<Item = (&Guess, GuessResult)> + '_ {
+        self.history
+            .iter()
+            .enumerate()
+            .map(move |(i, guess)| (guess, self.evaluate(guess, i as u32 + 1)))
+    }
+
+    /// Counts the most recent guesses forming a run where each one is strictly closer to the secret than the one before.
+    /// The run starts at the guess before the first improvement, so any non-empty history has a streak of at least `1`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(50).unwrap(), 10);
+    /// for val in [60, 55, 52, 51] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// assert_eq!(session.approaching_streak(), 4);
+    /// ```
+    pub fn approaching_streak(&self) -> u32 {
+        self.improvement_streaks().last().unwrap_or(0)
+    }
+
+    /// Counts the most recent guesses forming a run of strictly shrinking distances from the secret,
+    /// the same as `approaching_streak()`.
+    pub fn monotonic_improvement_streak(&self) -> u32 {
+        self.approaching_streak()
+    }
+
+    /// Counts the guesses of the longest run anywhere in the history
+    /// where each one is strictly closer to the secret than the one before.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(50).unwrap(), 10);
+    /// for val in [80, 70, 60, 90, 40] {
+    ///     session.attempt(Guess::new(val).unwrap());
+    /// }
+    /// 
+    /// assert_eq!(session.approaching_streak(), 2);
+    /// assert_eq!(session.longest_improvement_streak(), 3);
+    /// ```
+    pub fn longest_improvement_streak(&self) -> u32 {
+        self.improvement_streaks().max().unwrap_or(0)
+    }
+
+    /// Checks if every guess was strictly closer to the secret than the one before.
+    /// This is trivially `true` for fewer than two guesses.
+    pub fn is_monotonically_approaching(&self) -> bool {
+        self.approaching_streak() as usize == self.history.len()
+    }
+
+    /// Draws the number line from 0 to 100 between brackets, one character per value.
+    /// Guessed values show the attempt number, `1` to `9`, or `*` from the tenth attempt onward,
+    /// with later attempts drawn over earlier ones. The secret is always shown as `X`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(3).unwrap(), 10);
+    /// session.attempt(Guess::new(1).unwrap());
+    /// 
+    /// let board = session.format_ascii_board();
+    /// 
+    /// assert!(board.starts_with("[.1.X...."));
+    /// assert_eq!(board.len(), 103);
+    /// ```
+    pub fn format_ascii_board(&self) -> String {
+        let mut cells = [b'.'; GUESS_RANGE_SIZE];
+        for (i, guess) in self.history.iter().enumerate() {
+            cells[guess.val as usize] = match i + 1 {
+                attempt @ 1..=9 => b'0' + attempt as u8,
+                _ => b'*',
+            };
+        }
+        cells[self.secret.val as usize] = b'X';
+
+        let mut board = String::with_capacity(GUESS_RANGE_SIZE + 2);
+        board.push('[');
+        board.extend(cells.iter().map(|&cell| cell as char));
+        board.push(']');
+        board
+    }
+
+    /// Prints the board drawn by `format_ascii_board()` to the standard output.
+    #[cfg(feature = "std")]
+    pub fn print_ascii_board(&self) {
+        println!("{}", self.format_ascii_board());
+    }
+
+    /// Exports the history as comma-separated values, with a header row and one row per guess.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// 
+    /// assert_eq!(session.export_csv(), "attempt,guess,result,distance_from_secret\n1,50,Too high by 8.,8\n");
+    /// ```
+    pub fn export_csv(&self) -> String {
+        self.export_delimited(',')
+    }
+
+    /// Exports the history as tab-separated values, with a header row and one row per guess.
+    pub fn export_tsv(&self) -> String {
+        self.export_delimited('\t')
+    }
+
+    /// Exports the history as a Markdown table, with a header row and one row per guess,
+    /// ready to be pasted into issues, pull request comments or documentation.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// 
+    /// assert_eq!(
+    ///     session.export_markdown(),
+    ///     "| Attempt | Guess | Result | Distance |\n|---|---|---|---|\n| 1 | 50 | Too high by 8. | 8 |\n"
+    /// );
+    /// ```
+    pub fn export_markdown(&self) -> String {
+        let mut out = String::from("| Attempt | Guess | Result | Distance |\n|---|---|---|---|\n");
+
+        for (i, (guess, result)) in self.replay().enumerate() {
+            let attempt = i + 1;
+            let distance = guess.abs_diff(&self.secret);
+
+            out.push_str(&format!("| {} | {} | {} | {} |\n", attempt, guess, result, distance));
+        }
+
+        out
+    }
+
+    fn export_delimited(&self, sep: char) -> String {
+        let mut out = ["attempt", "guess", "result", "distance_from_secret"].join(&sep.to_string());
+        out.push('\n');
+
+        for (i, (guess, result)) in self.replay().enumerate() {
+            let attempt = i + 1;
+            let distance = guess.abs_diff(&self.secret);
+
+            out.push_str(&format!("{}{sep}{}{sep}{}{sep}{}\n", attempt, guess, result, distance, sep = sep));
+        }
+
+        out
+    }
+
+    /// Exports the session as a JSON object holding the secret, the config, the attempts and the history.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// 
+    /// assert_eq!(
+    ///     session.export_json(),
+    ///     concat!(
+    ///         r#"{"secret":42,"max_attempts":5,"allow_repeats":true,"track_distances":true,"hint_mode":"HighLow","#,
+    ///         r#""attempts_used":1,"won":false,"history":[50]}"#
+    ///     )
+    /// );
+    /// ```
+    pub fn export_json(&self) -> String {
+        let history: Vec<String> = self.history.iter().map(|g| g.to_string()).collect();
+
+        format!(
+            concat!(
+                r#"{{"secret":{},"max_attempts":{},"allow_repeats":{},"track_distances":{},"hint_mode":"{}","#,
+                r#""attempts_used":{},"won":{},"history":[{}]}}"#
+            ),
+            self.secret,
+            self.config.max_attempts,
+            self.config.allow_repeats,
+            self.config.track_distances,
+            self.config.hint_mode.name(),
+            self.attempts_used,
+            self.won,
+            history.join(",")
+        )
+    }
+
+    /// Exports the replay of the session as a JSON array with one object per guess, holding
+    /// the attempt number counting from `1`, the guess, the result and the distance from the secret.
+    /// The distance is `null` for results that do not compare the guess, such as `GameOver`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 1);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// 
+    /// assert_eq!(
+    ///     session.replay_as_json(),
+    ///     r#"[{"attempt":1,"guess":50,"result":"Game over! No attempts left.","distance":null}]"#
+    /// );
+    /// ```
+    pub fn replay_as_json(&self) -> String {
+        let steps: Vec<String> = self
+            .replay()
+            .enumerate()
+            .map(|(i, (guess, result))| {
+                let distance = match result {
+                    GuessResult::AlreadyGuessed | GuessResult::GameOver => "null".to_string(),
+                    _ => guess.abs_diff(&self.secret).to_string(),
+                };
+
+                format!(
+                    r#"{{"attempt":{},"guess":{},"result":"{}","distance":{}}}"#,
+                    i + 1,
+                    guess,
+                    result,
+                    distance
+                )
+            })
+            .collect();
+
+        format!("[{}]", steps.join(","))
+    }
+
+    /// Creates a session from a JSON object, the inverse of `export_json()`.
+    /// The config fields besides `max_attempts` are optional and take their default values when missing.
+    /// An error will be returned if the input is not a JSON object with the exported fields,
+    /// if a guess is outside (0..101) range, or if the fields contradict each other.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// 
+    /// assert_eq!(GuessSession::from_json(&session.export_json()), Ok(session));
+    /// assert!(GuessSession::from_json("{}").is_err());
+    /// ```
+    pub fn from_json(s: &str) -> Result<GuessSession, GuessError> {
+        let invalid = || GuessError::InvalidFormat(s.to_string());
+        let fields = json::parse_object(s).ok_or_else(invalid)?;
+
+        let field = |name: &str| fields.iter().find(|(key, _)| key == name).map(|(_, value)| value);
+        let number = |name: &str| match field(name) {
+            Some(json::Value::Number(n)) => Some(*n),
+            _ => None,
+        };
+        let to_guess = |n: i64| Ok(Guess::new(i32::try_from(n).map_err(|_| invalid())?)?);
+
+        let secret = to_guess(number("secret").ok_or_else(invalid)?)?;
+        let max_attempts = number("max_attempts").and_then(|n| u32::try_from(n).ok()).ok_or_else(invalid)?;
+        let attempts_used = number("attempts_used").and_then(|n| u32::try_from(n).ok()).ok_or_else(invalid)?;
+        let flag = |name: &str, default: bool| match field(name) {
+            Some(json::Value::Bool(b)) => Ok(*b),
+            None => Ok(default),
+            _ => Err(invalid()),
+        };
+        let won = match field("won") {
+            Some(json::Value::Bool(b)) => *b,
+            _ => return Err(invalid()),
+        };
+        let history = match field("history") {
+            Some(json::Value::Array(values)) => values.iter().map(|&n| to_guess(n)).collect::<Result<Vec<_>, GuessError>>()?,
+            _ => return Err(invalid()),
+        };
+
+        let default = GuessSessionConfig::default();
+        let hint_mode = match field("hint_mode") {
+            Some(json::Value::String(name)) => HintMode::from_name(name).ok_or_else(invalid)?,
+            None => default.hint_mode,
+            _ => return Err(invalid()),
+        };
+        let config = GuessSessionConfig {
+            max_attempts,
+            allow_repeats: flag("allow_repeats", default.allow_repeats)?,
+            track_distances: flag("track_distances", default.track_distances)?,
+            hint_mode,
+        };
+
+        if !GuessSession::is_consistent(&secret, &config, attempts_used, &history, won) {
+            return Err(invalid());
+        }
+
+        Ok(GuessSession::restore(secret, config, attempts_used, history, won))
+    }
+
+    /// Encodes the session in a compact binary format:
+    /// 1 byte for the secret, then `max_attempts` and `attempts_used` as big-endian `u32`s, the won flag,
+    /// the history length, one byte per guess and finally the rest of the config packed into a byte.
+    /// Returns `None` if the history is too long for its length byte, holding more than 255 guesses.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// 
+    /// assert_eq!(session.serialize_to_bytes().unwrap(), [42, 0, 0, 0, 5, 0, 0, 0, 1, 0, 1, 50, 7]);
+    /// ```
+    pub fn serialize_to_bytes(&self) -> Option<Vec<u8>> {
+        let len = u8::try_from(self.history.len()).ok()?;
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + len as usize + 1);
+        bytes.extend(self.secret.to_bytes());
+        bytes.extend(self.config.max_attempts.to_be_bytes());
+        bytes.extend(self.attempts_used.to_be_bytes());
+        bytes.push(self.won as u8);
+        bytes.push(len);
+        bytes.extend(self.history.iter().flat_map(|g| g.to_bytes()));
+        bytes.push(self.config.to_flags());
+        Some(bytes)
+    }
+
+    /// Decodes a session from bytes, the inverse of `serialize_to_bytes()`.
+    /// The config byte after the history is optional, sessions encoded without it get the default config.
+    /// An error will be returned if the bytes are malformed, or if the decoded fields contradict each other.
+    /// # Example
+    /// ```
+    /// use guessing_utils::{Guess, GuessSession};
+    /// 
+    /// let mut session = GuessSession::new(Guess::new(42).unwrap(), 5);
+    /// session.attempt(Guess::new(50).unwrap());
+    /// 
+    /// assert_eq!(GuessSession::deserialize_from_bytes(&session.serialize_to_bytes().unwrap()), Ok(session));
+    /// assert!(GuessSession::deserialize_from_bytes(&[42, 0, 0]).is_err());
+    /// ```
+    pub fn deserialize_from_bytes(bytes: &[u8]) -> Result<GuessSession, DeserializeError> {
+        let truncated = |expected| DeserializeError::Truncated { expected, actual: bytes.len() };
+        let to_guess = |byte: u8| Guess::from_bytes([byte]).map_err(|_| DeserializeError::InvalidGuess(byte));
+
+        if bytes.len() < HEADER_LEN {
+            return Err(truncated(HEADER_LEN));
+        }
+
+        let secret = to_guess(bytes[0])?;
+        let max_attempts = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+        let attempts_used = u32::from_be_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]);
+        let won = match bytes[9] {
+            0 => false,
+            1 => true,
+            flag => return Err(DeserializeError::InvalidFlag(flag)),
+        };
+
+        let expected = HEADER_LEN + bytes[10] as usize;
+        if bytes.len() < expected {
+            return Err(truncated(expected));
+        }
+
+        let config = match bytes[expected..] {
+            [] => GuessSessionConfig { max_attempts, ..GuessSessionConfig::default() },
+            [flags] => GuessSessionConfig::from_flags(max_attempts, flags).ok_or(DeserializeError::InvalidFlag(flags))?,
+            [_, ref trailing @ ..] => return Err(DeserializeError::TrailingBytes(trailing.len())),
+        };
+
+        let history: Vec<Guess> = bytes[HEADER_LEN..expected].iter().map(|&byte| to_guess(byte)).collect::<Result<_, _>>()?;
+
+        if !GuessSession::is_consistent(&secret, &config, attempts_used, &history, won) {
+            return Err(DeserializeError::Inconsistent);
+        }
+
+        Ok(GuessSession::restore(secret, config, attempts_used, history, won))
+    }
+
+    /// Checks if decoded fields describe a session which could have been played:
+    /// one guess per attempt used, no more attempts used than allowed,
+    /// no repeated guesses unless the config allows them,
+    /// and the secret only guessed as the last attempt of a won game.
+    fn is_consistent(secret: &Guess, config: &GuessSessionConfig, attempts_used: u32, history: &[Guess], won: bool) -> bool {
+        let solved_at = history.iter().position(|g| g == secret);
+
+        let won_consistent = match solved_at {
+            Some(i) => won && i + 1 == history.len(),
+            None => !won,
+        };
+
+        let mut seen = [false; GUESS_RANGE_SIZE];
+        let repeats_consistent = config.allow_repeats || history.iter().all(|g| !core::mem::replace(&mut seen[g.val as usize], true));
+
+        attempts_used <= config.max_attempts && history.len() == attempts_used as usize && won_consistent && repeats_consistent
+    }
+
+    /// Rebuilds a decoded session, recovering the search bounds from the history.
+    fn restore(secret: Guess, config: GuessSessionConfig, attempts_used: u32, history: Vec<Guess>, won: bool) -> GuessSession {
+        let mut session = GuessSession { attempts_used, won, ..GuessSession::new_with_config(secret, config) };
+
+        for (i, guess) in history.iter().enumerate() {
+            let result = session.evaluate(guess, i as u32 + 1);
+            session.narrow(guess, &result);
+        }
+        session.history = history;
+
+        session
+    }
+
+    /// Narrows the search bounds by the hint given for a guess.
+    fn narrow(&mut self, guess: &Guess, result: &GuessResult) {
+        match result {
+            GuessResult::TooLow { .. } => self.lo_bound = self.lo_bound.max(guess.val + 1),
+            GuessResult::TooHigh { .. } => self.hi_bound = self.hi_bound.min(guess.val - 1),
+            _ => {}
+        }
+    }
+
+    /// Iterates over the distances of the guesses from the secret.
+    fn errors(&self) -> impl Iterator<Item = u32> + '_ {
+        self.history.iter().map(|g| g.abs_diff(&self.secret))
+    }
+
+    /// Iterates over the length of the improvement run ending at each guess.
+    fn improvement_streaks(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut previous = None;
+
+        self.errors().scan(0, move |streak, error| {
+            *streak = match previous {
+                Some(p) if error < p => *streak + 1,
+                _ => 1,
+            };
+            previous = Some(error);
+            Some(*streak)
+        })
+    }
+
+    /// Evaluates a guess made on the `attempt`-th attempt, counting from `1`.
+    fn evaluate(&self, guess: &Guess, attempt: u32) -> GuessResult {
+        let distance = guess.abs_diff(&self.secret);
+
+        match guess.cmp(&self.secret) {
+            Ordering::Equal => GuessResult::Correct,
+            _ if attempt >= self.config.max_attempts => GuessResult::GameOver,
+            Ordering::Greater => GuessResult::TooHigh { distance },
+            Ordering::Less => GuessResult::TooLow { distance },
+        }
+    }
+}
+
+/// The serialized form of a session, leaving out the search bounds which are recovered from the history.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SessionData {
+    secret: Guess,
+    config: GuessSessionConfig,
+    attempts_used: u32,
+    history: Vec<Guess>,
+    won: bool,
+    rounds: u32,
+}
+
+#[cfg(feature = "serde")]
+impl From<GuessSession> for SessionData {
+    fn from(session: GuessSession) -> Self {
+        SessionData {
+            secret: session.secret,
+            config: session.config,
+            attempts_used: session.attempts_used,
+            history: session.history,
+            won: session.won,
+            rounds: session.rounds,
+        }
+    }
+}
+
+/// Validates deserialized sessions the same way as `GuessSession::from_json`.
+#[cfg(feature = "serde")]
+impl TryFrom<SessionData> for GuessSession {
+    type Error = DeserializeError;
+
+    fn try_from(data: SessionData) -> Result<Self, Self::Error> {
+        let SessionData { secret, config, attempts_used, history, won, rounds } = data;
+
+        if !GuessSession::is_consistent(&secret, &config, attempts_used, &history, won) {
+            return Err(DeserializeError::Inconsistent);
+        }
+
+        Ok(GuessSession { rounds, ..GuessSession::restore(secret, config, attempts_used, history, won) })
+    }
+}
+
+/// A minimal JSON reader for the flat objects written by `GuessSession::export_json()`.
+mod json {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::iter::Peekable;
+    use core::str::Chars;
+
+    pub enum Value {
+        Number(i64),
+        Bool(bool),
+        Array(Vec<i64>),
+        String(String),
+    }
+
+    /// Parses an object whose values are integers, booleans, strings or arrays of integers.
+    pub fn parse_object(s: &str) -> Option<Vec<(String, Value)>> {
+        let mut chars = s.chars().peekable();
+        let mut fields = Vec::new();
+
+        expect(&mut chars, '{')?;
+        if !peek_is(&mut chars, '}') {
+            loop {
+                let key = parse_string(&mut chars)?;
+                expect(&mut chars, ':')?;
+                fields.push((key, parse_value(&mut chars)?));
+
+                if !peek_is(&mut chars, ',') {
+                    break;
+                }
+                chars.next();
+            }
+        }
+        expect(&mut chars, '}')?;
+
+        skip_whitespace(&mut chars);
+        chars.next().is_none().then_some(fields)
+    }
+
+    fn parse_value(chars: &mut Peekable<Chars>) -> Option<Value> {
+        skip_whitespace(chars);
+
+        match chars.peek()? {
+            '[' => {
+                chars.next();
+                let mut values = Vec::new();
+                if !peek_is(chars, ']') {
+                    loop {
+                        values.push(parse_number(chars)?);
+                        if !peek_is(chars, ',') {
+                            break;
+                        }
+                        chars.next();
+                    }
+                }
+                expect(chars, ']')?;
+                Some(Value::Array(values))
+            }
+            '"' => parse_string(chars).map(Value::String),
+            't' | 'f' => {
+                let word: String = core::iter::from_fn(|| chars.next_if(|c| c.is_ascii_alphabetic())).collect();
+                match word.as_str() {
+                    "true" => Some(Value::Bool(true)),
+                    "false" => Some(Value::Bool(false)),
+                    _ => None,
+                }
+            }
+            _ => parse_number(chars).map(Value::Number),
+        }
+    }
+
+    fn parse_number(chars: &mut Peekable<Chars>) -> Option<i64> {
+        skip_whitespace(chars);
+
+        let digits: String = core::iter::from_fn(|| chars.next_if(|&c| c == '-' || c.is_ascii_digit())).collect();
+        digits.parse().ok()
+    }
+
+    fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+        expect(chars, '"')?;
+
+        let text: String = core::iter::from_fn(|| chars.next_if(|&c| c != '"')).collect();
+        chars.next()?;
+        Some(text)
+    }
+
+    fn expect(chars: &mut Peekable<Chars>, expected: char) -> Option<()> {
+        skip_whitespace(chars);
+        chars.next_if_eq(&expected).map(|_| ())
+    }
+
+    fn peek_is(chars: &mut Peekable<Chars>, expected: char) -> bool {
+        skip_whitespace(chars);
+        chars.peek() == Some(&expected)
+    }
+
+    fn skip_whitespace(chars: &mut Peekable<Chars>) {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(val: i32) -> Guess {
+        Guess::new(val).unwrap()
+    }
+
+    #[test]
+    fn attempt_test() {
+        let mut session = GuessSession::new(guess(42), 5);
+
+        assert_eq!(session.attempt(guess(70)), GuessResult::TooHigh { distance: 28 });
+        assert_eq!(session.attempt(guess(10)), GuessResult::TooLow { distance: 32 });
+        assert_eq!(session.attempt(guess(42)), GuessResult::Correct);
+        assert!(session.won);
+        assert_eq!(session.attempts_used, 3);
+        assert_eq!(session.history, [guess(70), guess(10), guess(42)]);
+    }
+
+    #[test]
+    fn max_attempts_test() {
+        let mut session = GuessSession::new(guess(42), 2);
+
+        assert_eq!(session.attempt(guess(0)), GuessResult::TooLow { distance: 42 });
+        assert_eq!(session.attempt(guess(100)), GuessResult::GameOver);
+        assert_eq!(session.attempt(guess(42)), GuessResult::GameOver);
+        assert!(!session.won);
+        assert_eq!(session.attempts_used, 2);
+        assert_eq!(session.history.len(), 2);
+    }
+
+    #[test]
+    fn correct_on_last_attempt_test() {
+        let mut session = GuessSession::new(guess(42), 1);
+
+        assert_eq!(session.attempt(guess(42)), GuessResult::Correct);
+        assert_eq!(session.attempt(guess(42)), GuessResult::GameOver);
+        assert!(session.won);
+        assert_eq!(session.attempts_used, 1);
+
+        let mut empty = GuessSession::new(guess(42), 0);
+        assert_eq!(empty.attempt(guess(42)), GuessResult::GameOver);
+        assert!(empty.history.is_empty());
+    }
+
+    #[test]
+    fn new_random_test() {
+        let session = GuessSession::new_random(10);
+
+        assert_eq!(session.max_attempts(), 10);
+        assert!((0..=100).contains(&session.secret().get()));
+        assert!(session.history.is_empty());
+    }
+
+    #[test]
+    fn result_distance_test() {
+        let secret = guess(42);
+        let mut session = GuessSession::new(secret, 200);
+
+        for val in 0..=100 {
+            let g = guess(val);
+            let result = session.evaluate(&g, 1);
+
+            assert_eq!(result.is_correct(), g == secret);
+            assert_eq!(result.distance().unwrap_or(0), g.abs_diff(&secret));
+            assert_eq!(matches!(result, GuessResult::TooHigh { .. }), val > 42);
+            assert_eq!(matches!(result, GuessResult::TooLow { .. }), val < 42);
+        }
+
+        assert!(session.attempt(guess(41)).distance() == Some(1));
+        assert!(session.attempt(secret).is_terminal());
+        assert!(session.attempt(secret).is_terminal());
+    }
+
+    #[test]
+    fn state_test() {
+        let mut session = GuessSession::new(guess(42), 5);
+
+        assert!(!session.is_won());
+        assert!(!session.is_lost());
+        assert!(!session.is_over());
+        assert_eq!(session.attempts_remaining(), 5);
+        assert_eq!(session.attempts_used(), 0);
+        assert!(session.history().is_empty());
+
+        session.attempt(guess(50));
+        session.attempt(guess(25));
+
+        assert!(!session.is_won());
+        assert!(!session.is_lost());
+        assert!(!session.is_over());
+        assert_eq!(session.attempts_remaining(), 3);
+        assert_eq!(session.attempts_used(), 2);
+        assert_eq!(session.history(), [guess(50), guess(25)]);
+
+        session.attempt(guess(42));
+
+        assert!(session.is_won());
+        assert!(!session.is_lost());
+        assert!(session.is_over());
+        assert_eq!(session.attempts_remaining(), 2);
+        assert_eq!(session.attempts_used(), 3);
+        assert_eq!(session.history().last(), Some(&guess(42)));
+    }
+
+    #[test]
+    fn lost_state_test() {
+        let mut session = GuessSession::new(guess(42), 2);
+        session.attempt(guess(1));
+        session.attempt(guess(2));
+
+        assert!(!session.is_won());
+        assert!(session.is_lost());
+        assert!(session.is_over());
+        assert_eq!(session.attempts_remaining(), 0);
+
+        let mut unlimited = GuessSession::new(guess(42), u32::MAX);
+        unlimited.attempt(guess(1));
+        assert_eq!(unlimited.attempts_remaining(), u32::MAX - 1);
+        assert!(!unlimited.is_over());
+    }
+
+    #[test]
+    fn multi_round_test() {
+        let mut session = GuessSession::new(guess(10), 3);
+
+        for (round, secret) in [20, 30, 40].into_iter().enumerate() {
+            session.attempt(guess(0));
+            session.attempt(session.secret());
+            assert!(session.is_won());
+
+            session.reset_with_secret(guess(secret));
+
+            assert_eq!(session.round_count(), round as u32 + 1);
+            assert_eq!(session.secret(), guess(secret));
+            assert!(session.history().is_empty());
+            assert!(!session.is_won());
+            assert!(!session.is_over());
+            assert_eq!(session.attempts_used(), 0);
+            assert_eq!(session.attempts_remaining(), 3);
+            assert_eq!(session.attempt(guess(secret + 1)), GuessResult::TooHigh { distance: 1 });
+        }
+
+        session.reset();
+        assert_eq!(session.round_count(), 4);
+        assert!(session.history().is_empty());
+        assert_eq!(session.attempt(session.secret()), GuessResult::Correct);
+    }
+
+    #[test]
+    fn statistics_test() {
+        let mut session = GuessSession::new(guess(40), 10);
+
+        assert!(session.statistics().is_none());
+        assert!(session.average_distance_from_secret().is_none());
+
+        // Distances from 40 are 10, 20, 5 and 15.
+        for val in [50, 20, 45, 55] {
+            session.attempt(guess(val));
+        }
+
+        let stats = session.statistics().unwrap();
+        assert_eq!(stats.mean(), 42.5);
+        assert_eq!(stats.min(), guess(20));
+        assert_eq!(stats.max(), guess(55));
+        assert_eq!(session.statistics().map(|s| s.mean()), Some(42.5));
+        assert_eq!(session.average_distance_from_secret(), Some(12.5));
+    }
+
+    fn play(secret: i32, max_attempts: u32, guesses: &[i32]) -> GuessSession {
+        let mut session = GuessSession::new(guess(secret), max_attempts);
+        for &val in guesses {
+            session.attempt(guess(val));
+        }
+        session
+    }
+
+    #[test]
+    fn first_correct_at_test() {
+        assert_eq!(play(42, 5, &[42]).first_correct_at(), Some(0));
+        assert_eq!(play(42, 5, &[10, 60, 42]).first_correct_at(), Some(2));
+        assert_eq!(play(42, 3, &[10, 60, 41]).first_correct_at(), None);
+        assert_eq!(play(42, 3, &[]).first_correct_at(), None);
+    }
+
+    #[test]
+    fn history_accessors_test() {
+        let session = play(42, 5, &[10, 60, 42]);
+
+        assert_eq!(session.last_guess(), Some(&guess(42)));
+        assert_eq!(session.nth_guess(0), Some(&guess(10)));
+        assert_eq!(session.nth_guess(2), Some(&guess(42)));
+        assert_eq!(session.nth_guess(3), None);
+        assert_eq!(session.guesses_since(0), session.history());
+        assert_eq!(session.guesses_since(1), [guess(60), guess(42)]);
+        assert!(session.guesses_since(3).is_empty());
+        assert!(session.guesses_since(100).is_empty());
+        assert_eq!(play(42, 5, &[]).last_guess(), None);
+    }
+
+    #[test]
+    fn best_worst_guess_test() {
+        let session = play(50, 10, &[20, 45, 90, 30]);
+
+        assert_eq!(session.best_guess(), Some(&guess(45)));
+        assert_eq!(session.worst_guess(), Some(&guess(90)));
+
+        // 45 and 55 are both 5 away, 10 and 90 are both 40 away.
+        let ties = play(50, 10, &[10, 45, 55, 90]);
+        assert!(std::ptr::eq(ties.best_guess().unwrap(), &ties.history()[1]));
+        assert!(std::ptr::eq(ties.worst_guess().unwrap(), &ties.history()[0]));
+
+        let empty = play(50, 10, &[]);
+        assert_eq!(empty.best_guess(), None);
+        assert_eq!(empty.worst_guess(), None);
+        assert!(empty.best_n_guesses(3).is_empty());
+    }
+
+    #[test]
+    fn best_n_guesses_test() {
+        let session = play(50, 10, &[10, 55, 90, 45, 48]);
+        let values = |n| session.best_n_guesses(n).iter().map(|g| g.get()).collect::<Vec<_>>();
+
+        assert!(values(0).is_empty());
+        assert_eq!(values(1), [48]);
+        assert_eq!(values(3), [48, 55, 45]);
+        assert_eq!(values(5), [48, 55, 45, 10, 90]);
+        assert_eq!(values(50).len(), 5);
+    }
+
+    #[test]
+    fn best_guess_consistency_test() {
+        let session = play(50, 10, &[10, 45, 50, 49]);
+        let index = session.first_correct_at().unwrap();
+
+        assert_eq!(session.best_guess(), session.nth_guess(index));
+        assert_eq!(session.best_guess(), Some(&session.secret()));
+    }
+
+    #[test]
+    fn error_summary_test() {
+        let session = play(50, 10, &[20, 45, 90, 30]);
+
+        assert_eq!(session.average_error(), Some(23.75));
+        assert_eq!(session.average_error(), session.average_distance_from_secret());
+        assert_eq!(session.max_error(), Some(40));
+        assert_eq!(session.min_error(), Some(5));
+
+        let empty = play(50, 10, &[]);
+        assert_eq!(empty.average_error(), None);
+        assert_eq!(empty.max_error(), None);
+        assert_eq!(empty.min_error(), None);
+    }
+
+    #[test]
+    fn error_improvement_test() {
+        assert_eq!(play(50, 10, &[0, 10, 20, 30, 40]).error_improvement(), Some(-10.0));
+        assert_eq!(play(50, 10, &[40, 30, 20]).error_improvement(), Some(10.0));
+        assert_eq!(play(50, 10, &[40, 60, 40, 60]).error_improvement(), Some(0.0));
+        assert!(play(50, 10, &[0, 49, 48, 47, 3]).error_improvement().unwrap() < 0.0);
+        assert_eq!(play(50, 10, &[40]).error_improvement(), None);
+        assert_eq!(play(50, 10, &[]).error_improvement(), None);
+
+        let mut random = GuessSession::new(guess(50), 10_000);
+        for g in crate::gen_random_seeded_sequence(1, 5_000).into_iter().filter(|g| g.get() != 50) {
+            random.attempt(g);
+        }
+        assert!(random.error_improvement().unwrap().abs() < 0.01);
+    }
+
+    #[test]
+    fn export_csv_test() {
+        let session = play(42, 3, &[50, 30, 42]);
+        let csv = session.export_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "attempt,guess,result,distance_from_secret");
+        assert_eq!(lines.len() as u32 - 1, session.attempts_used());
+        assert_eq!(lines[1], "1,50,Too high by 8.,8");
+        assert_eq!(lines[2], "2,30,Too low by 12.,12");
+        assert_eq!(lines[3], "3,42,Correct! You guessed the number.,0");
+
+        for line in &lines {
+            assert_eq!(line.split(',').count(), 4);
+        }
+    }
+
+    #[test]
+    fn export_tsv_test() {
+        let session = play(42, 2, &[50, 30]);
+        let tsv = session.export_tsv();
+        let rows: Vec<Vec<&str>> = tsv.lines().map(|line| line.split('\t').collect()).collect();
+
+        assert_eq!(rows[0], ["attempt", "guess", "result", "distance_from_secret"]);
+        assert_eq!(rows[1], ["1", "50", "Too high by 8.", "8"]);
+        assert_eq!(rows[2], ["2", "30", "Game over! No attempts left.", "12"]);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(play(42, 2, &[]).export_tsv(), "attempt\tguess\tresult\tdistance_from_secret\n");
+    }
+
+    #[test]
+    fn export_markdown_test() {
+        let session = play(42, 5, &[50, 30, 42]);
+        let markdown = session.export_markdown();
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        assert_eq!(lines[0], "| Attempt | Guess | Result | Distance |");
+        assert!(lines[1].split('|').filter(|cell| !cell.is_empty()).all(|cell| cell == "---"));
+        assert_eq!(lines.len() - 2, session.history().len());
+        assert_eq!(lines[2], "| 1 | 50 | Too high by 8. | 8 |");
+        assert_eq!(lines[4], "| 3 | 42 | Correct! You guessed the number. | 0 |");
+
+        for (line, g) in lines[2..].iter().zip(session.history()) {
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+
+            assert_eq!(cells.len(), 6);
+            assert_eq!(cells[2], g.to_string());
+        }
+
+        assert_eq!(play(42, 2, &[]).export_markdown().lines().count(), 2);
+    }
+
+    #[test]
+    fn export_json_test() {
+        let session = play(42, 5, &[50, 30, 42]);
+        let json = session.export_json();
+
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"secret":42,"max_attempts":5,"allow_repeats":true,"track_distances":true,"hint_mode":"HighLow","#,
+                r#""attempts_used":3,"won":true,"history":[50,30,42]}"#
+            )
+        );
+        assert!(json.starts_with('{'));
+        assert!(json.ends_with('}'));
+        assert!(json.contains(r#""secret":42"#));
+        assert_eq!(GuessSession::from_json(&json), Ok(session));
+    }
+
+    #[test]
+    fn json_round_trip_test() {
+        for session in [play(42, 5, &[]), play(42, 2, &[1, 2]), play(0, 10, &[50, 25, 12, 6, 3, 1, 0]), play(7, 0, &[])] {
+            let restored = GuessSession::from_json(&session.export_json()).unwrap();
+
+            assert_eq!(restored, session);
+            assert_eq!(restored.secret(), session.secret());
+            assert_eq!(restored.history().len(), session.history().len());
+        }
+
+        let spaced = "{ \"history\" : [ 50 , 42 ] , \"won\" : true, \"secret\": 42,\n\"attempts_used\": 2, \"max_attempts\": 3 }";
+        assert_eq!(GuessSession::from_json(spaced), Ok(play(42, 3, &[50, 42])));
+    }
+
+    #[test]
+    fn from_json_invalid_test() {
+        let invalid = [
+            "",
+            "{}",
+            "[]",
+            r#"{"secret":42,"max_attempts":5,"attempts_used":1,"won":false,"history":[50]"#,
+            r#"{"secret":42,"max_attempts":5,"attempts_used":1,"won":false,"history":[50]} x"#,
+            r#"{"secret":42,"max_attempts":5,"attempts_used":1,"won":"no","history":[50]}"#,
+            r#"{"secret":42,"max_attempts":-5,"attempts_used":1,"won":false,"history":[50]}"#,
+            r#"{"secret":42,"max_attempts":5,"attempts_used":2,"won":false,"history":[50]}"#,
+            r#"{"secret":42,"max_attempts":5,"attempts_used":1,"won":true,"history":[50]}"#,
+            r#"{"secret":42,"max_attempts":0,"attempts_used":1,"won":false,"history":[50]}"#,
+            r#"{"secret":5000000000,"max_attempts":5,"attempts_used":0,"won":false,"history":[]}"#,
+            r#"{"secret":42,"max_attempts":5,"attempts_used":1,"won":false,"history":[-5000000000]}"#,
+        ];
+        for input in invalid {
+            assert_eq!(GuessSession::from_json(input), Err(GuessError::InvalidFormat(input.to_string())));
+        }
+
+        let out_of_range = r#"{"secret":142,"max_attempts":5,"attempts_used":0,"won":false,"history":[]}"#;
+        assert!(matches!(GuessSession::from_json(out_of_range), Err(GuessError::RangeError(_))));
+    }
+
+    #[test]
+    fn config_round_trip_test() {
+        let configs = [
+            GuessSessionConfig::default(),
+            GuessSessionConfig::default().max_attempts(4).no_repeats().hint_mode(HintMode::Temperature),
+            GuessSessionConfig::default().track_distances(false).hint_mode(HintMode::None),
+        ];
+
+        for config in configs {
+            let mut session = GuessSession::new_with_config(guess(42), config);
+            session.attempt(guess(50));
+            session.attempt(guess(30));
+
+            let from_json = GuessSession::from_json(&session.export_json()).unwrap();
+            let from_bytes = GuessSession::deserialize_from_bytes(&session.serialize_to_bytes().unwrap()).unwrap();
+
+            assert_eq!(from_json, session);
+            assert_eq!(from_bytes, session);
+            assert_eq!(from_json.config(), &config);
+        }
+
+        let mut session = GuessSession::new_with_config(guess(42), GuessSessionConfig::default().no_repeats());
+        session.attempt(guess(50));
+        let mut restored = GuessSession::from_json(&session.export_json()).unwrap();
+        assert_eq!(restored.attempt(guess(50)), GuessResult::AlreadyGuessed);
+
+        // Exports from before the config fields were added still load, with the default config.
+        let old = r#"{"secret":42,"max_attempts":5,"attempts_used":1,"won":false,"history":[50]}"#;
+        assert_eq!(GuessSession::from_json(old), Ok(play(42, 5, &[50])));
+
+        // So do byte encodings from before the config byte was added after the history.
+        let old = [42, 0, 0, 0, 5, 0, 0, 0, 1, 0, 1, 50];
+        assert_eq!(GuessSession::deserialize_from_bytes(&old), Ok(play(42, 5, &[50])));
+        let custom = GuessSessionConfig::default().max_attempts(5).no_repeats().hint_mode(HintMode::Temperature);
+        let restored = GuessSession::deserialize_from_bytes(&[&old[..], &[custom.to_flags()]].concat()).unwrap();
+        assert_eq!(restored.config(), &custom);
+
+        let invalid = [
+            r#"{"secret":42,"max_attempts":5,"hint_mode":"Loud","attempts_used":0,"won":false,"history":[]}"#,
+            r#"{"secret":42,"max_attempts":5,"allow_repeats":1,"attempts_used":0,"won":false,"history":[]}"#,
+            r#"{"secret":42,"max_attempts":5,"allow_repeats":false,"attempts_used":2,"won":false,"history":[50,50]}"#,
+        ];
+        for input in invalid {
+            assert_eq!(GuessSession::from_json(input), Err(GuessError::InvalidFormat(input.to_string())));
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip_test() {
+        let long: Vec<i32> = (0..100).chain(0..100).chain(0..100).collect();
+
+        for session in [play(42, 5, &[]), play(42, 3, &[10, 20, 42]), play(100, 2, &[0, 100]), play(100, 1000, &long[..255])] {
+            let bytes = session.serialize_to_bytes().unwrap();
+
+            assert_eq!(bytes.len(), 11 + session.history().len() + 1);
+            assert_eq!(GuessSession::deserialize_from_bytes(&bytes), Ok(session));
+        }
+
+        // A history too long for the length byte is refused rather than stored cut short.
+        let too_long = play(100, 1000, &long);
+        assert_eq!(too_long.serialize_to_bytes(), None);
+        assert_eq!(GuessSession::from_json(&too_long.export_json()), Ok(too_long));
+
+        let longest = play(100, 1000, &long[..255]);
+        let from_bytes = GuessSession::deserialize_from_bytes(&longest.serialize_to_bytes().unwrap()).unwrap();
+        assert_eq!(from_bytes.attempts_used(), 255);
+        assert_eq!(GuessSession::from_json(&from_bytes.export_json()), Ok(longest));
+    }
+
+    #[test]
+    fn deserialize_truncated_test() {
+        let bytes = play(42, 3, &[10, 20, 42]).serialize_to_bytes().unwrap();
+
+        for len in 0..11 {
+            assert_eq!(
+                GuessSession::deserialize_from_bytes(&bytes[..len]),
+                Err(DeserializeError::Truncated { expected: 11, actual: len })
+            );
+        }
+        assert_eq!(
+            GuessSession::deserialize_from_bytes(&bytes[..13]),
+            Err(DeserializeError::Truncated { expected: 14, actual: 13 })
+        );
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert_eq!(GuessSession::deserialize_from_bytes(&trailing), Err(DeserializeError::TrailingBytes(1)));
+    }
+
+    #[test]
+    fn deserialize_invalid_bytes_test() {
+        let bytes = play(42, 3, &[10, 20]).serialize_to_bytes().unwrap();
+
+        for byte in 101..=255u8 {
+            let mut secret = bytes.clone();
+            secret[0] = byte;
+            assert_eq!(GuessSession::deserialize_from_bytes(&secret), Err(DeserializeError::InvalidGuess(byte)));
+
+            let mut history = bytes.clone();
+            history[11] = byte;
+            assert_eq!(GuessSession::deserialize_from_bytes(&history), Err(DeserializeError::InvalidGuess(byte)));
+        }
+
+        let mut flag = bytes.clone();
+        flag[9] = 2;
+        assert_eq!(GuessSession::deserialize_from_bytes(&flag), Err(DeserializeError::InvalidFlag(2)));
+
+        let mut config = bytes.clone();
+        config[13] = 3 << 2;
+        assert_eq!(GuessSession::deserialize_from_bytes(&config), Err(DeserializeError::InvalidFlag(3 << 2)));
+    }
+
+    #[test]
+    fn deserialize_inconsistent_test() {
+        // The secret, `max_attempts`, `attempts_used`, the won flag and the history length, with the default config.
+        let header = |max: u8, used: u8, won: u8, len: u8| [42, 0, 0, 0, max, 0, 0, 0, used, won, len];
+        let decode = |header: [u8; 11], history: &[u8]| {
+            GuessSession::deserialize_from_bytes(&[&header[..], history].concat())
+        };
+
+        assert_eq!(decode(header(1, 5, 0, 0), &[]), Err(DeserializeError::Inconsistent));
+        assert_eq!(decode(header(5, 1, 1, 1), &[50]), Err(DeserializeError::Inconsistent));
+        assert_eq!(decode(header(5, 0, 1, 0), &[]), Err(DeserializeError::Inconsistent));
+        assert_eq!(decode(header(5, 2, 0, 2), &[50, 42]), Err(DeserializeError::Inconsistent));
+        assert_eq!(decode(header(5, 2, 1, 2), &[42, 50]), Err(DeserializeError::Inconsistent));
+        assert_eq!(decode(header(5, 2, 0, 1), &[50]), Err(DeserializeError::Inconsistent));
+        assert_eq!(decode(header(5, 1, 0, 2), &[50, 30]), Err(DeserializeError::Inconsistent));
+
+        assert_eq!(decode(header(5, 2, 1, 2), &[50, 42]), Ok(play(42, 5, &[50, 42])));
+        assert_eq!(decode(header(2, 2, 0, 2), &[50, 30]), Ok(play(42, 2, &[50, 30])));
+
+        // A history cut short is rejected, even when it holds as many guesses as the length byte allows.
+        let mut long = [100, 0, 0, 3, 232, 0, 0, 1, 44, 1, 255].to_vec();
+        long.extend((0..255).map(|i| (i % 100) as u8));
+        assert_eq!(GuessSession::deserialize_from_bytes(&long), Err(DeserializeError::Inconsistent));
+    }
+
+    #[test]
+    fn sorted_history_test() {
+        let session = play(42, 10, &[60, 10, 90, 10, 30, 60]);
+        let sorted = session.sorted_history();
+
+        assert_eq!(sorted.len(), session.history().len());
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(session.history()[0], guess(60));
+        assert!(play(42, 10, &[]).sorted_history().is_empty());
+    }
+
+    #[test]
+    fn unique_and_duplicate_guesses_test() {
+        let session = play(42, 10, &[60, 10, 90, 10, 30, 60, 60]);
+
+        assert_eq!(session.unique_history(), [guess(60), guess(10), guess(90), guess(30)]);
+        assert_eq!(session.duplicate_guesses(), [guess(60), guess(10)]);
+
+        let distinct = play(42, 10, &[1, 2, 3]);
+        assert_eq!(distinct.unique_history(), distinct.history());
+        assert!(distinct.duplicate_guesses().is_empty());
+
+        let empty = play(42, 10, &[]);
+        assert!(empty.unique_history().is_empty());
+        assert!(empty.duplicate_guesses().is_empty());
+    }
+
+    #[test]
+    fn contains_test() {
+        assert!(!play(42, 10, &[]).contains(&guess(42)));
+        assert!(play(42, 10, &[10]).contains(&guess(10)));
+        assert!(!play(42, 10, &[10]).contains(&guess(11)));
+
+        let session = play(42, 10, &[10, 60, 10, 10]);
+        assert!(session.contains(&guess(10)));
+        assert!(session.contains(&guess(60)));
+        assert!(!session.contains(&guess(42)));
+    }
+
+    #[test]
+    fn count_occurrences_test() {
+        let session = play(42, 10, &[10, 60, 10, 10]);
+
+        assert_eq!(session.count_occurrences(&guess(42)), 0);
+        assert_eq!(session.count_occurrences(&guess(60)), 1);
+        assert_eq!(session.count_occurrences(&guess(10)), 3);
+        assert_eq!(play(42, 10, &[]).count_occurrences(&guess(10)), 0);
+    }
+
+    #[test]
+    fn directional_queries_test() {
+        let session = play(42, 10, &[10, 60, 50, 40, 55, 100]);
+        let values = |guesses: Vec<&Guess>| guesses.iter().map(|g| g.get()).collect::<Vec<_>>();
+
+        assert_eq!(values(session.guesses_above(&guess(50))), [60, 55, 100]);
+        assert_eq!(values(session.guesses_below(&guess(50))), [10, 40]);
+        assert_eq!(values(session.guesses_within(&guess(50), 10)), [60, 50, 40, 55]);
+        assert_eq!(values(session.guesses_within(&guess(50), 0)), [50]);
+        assert_eq!(session.guesses_within(&guess(50), 100).len(), session.history().len());
+        assert!(session.guesses_above(&Guess::MAX).is_empty());
+        assert!(session.guesses_below(&Guess::MIN).is_empty());
+    }
+
+    #[test]
+    fn replay_won_test() {
+        let session = play(42, 5, &[50, 30, 42]);
+        let results: Vec<GuessResult> = session.replay().map(|(_, result)| result).collect();
+
+        assert_eq!(
+            results,
+            [GuessResult::TooHigh { distance: 8 }, GuessResult::TooLow { distance: 12 }, GuessResult::Correct]
+        );
+        assert_eq!(session.replay().last(), Some((&guess(42), GuessResult::Correct)));
+    }
+
+    #[test]
+    fn replay_lost_test() {
+        let session = play(42, 3, &[50, 30, 41]);
+        let results: Vec<GuessResult> = session.replay().map(|(_, result)| result).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2], GuessResult::GameOver);
+        assert!(results[..2].iter().all(|r| !r.is_terminal()));
+        assert_eq!(play(42, 3, &[]).replay().count(), 0);
+    }
+
+    #[test]
+    fn replay_matches_attempts_test() {
+        let mut session = GuessSession::new(guess(42), 2);
+        let played = [session.attempt(guess(10)), session.attempt(guess(42))];
+
+        let replayed: Vec<(&Guess, GuessResult)> = session.replay().collect();
+        assert_eq!(replayed, [(&guess(10), played[0]), (&guess(42), played[1])]);
+        assert_eq!(replayed[1].1, GuessResult::Correct);
+    }
+
+    #[test]
+    fn converging_streak_test() {
+        let session = play(50, 10, &[60, 55, 52, 51]);
+
+        assert_eq!(session.approaching_streak(), 4);
+        assert_eq!(session.longest_improvement_streak(), 4);
+        assert!(session.is_monotonically_approaching());
+
+        // Alternating sides of the secret still count as long as the distance shrinks.
+        let alternating = play(50, 10, &[10, 80, 35, 55, 50]);
+        assert_eq!(alternating.approaching_streak(), 5);
+        assert!(alternating.is_monotonically_approaching());
+    }
+
+    #[test]
+    fn diverging_streak_test() {
+        let session = play(50, 10, &[51, 55, 60, 90]);
+
+        assert_eq!(session.approaching_streak(), 1);
+        assert_eq!(session.longest_improvement_streak(), 1);
+        assert!(!session.is_monotonically_approaching());
+
+        // Staying at the same distance is not an improvement.
+        let same = play(50, 10, &[40, 60, 40]);
+        assert_eq!(same.longest_improvement_streak(), 1);
+        assert!(!same.is_monotonically_approaching());
+    }
+
+    #[test]
+    fn mixed_streak_test() {
+        let session = play(50, 10, &[80, 70, 60, 90, 40, 30, 45]);
+
+        assert_eq!(session.approaching_streak(), 2);
+        assert_eq!(session.longest_improvement_streak(), 3);
+        assert!(!session.is_monotonically_approaching());
+
+        let single = play(50, 10, &[10]);
+        assert_eq!(single.approaching_streak(), 1);
+        assert!(single.is_monotonically_approaching());
+
+        let empty = play(50, 10, &[]);
+        assert_eq!(empty.approaching_streak(), 0);
+        assert_eq!(empty.longest_improvement_streak(), 0);
+        assert!(empty.is_monotonically_approaching());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_session_test() {
+        let mut session = play(42, 5, &[50, 30]);
+        let json = serde_json::to_value(&session).unwrap();
+
+        assert_eq!(json["secret"], 42);
+        assert_eq!(json["config"]["max_attempts"], 5);
+        assert_eq!(json["history"], serde_json::json!([50, 30]));
+        assert!(json.get("lo_bound").is_none());
+        assert_eq!(serde_json::from_value::<GuessSession>(json).unwrap(), session);
+
+        session.reset_with_secret(guess(10));
+        session.attempt(guess(5));
+        let restored: GuessSession = serde_json::from_str(&serde_json::to_string(&session).unwrap()).unwrap();
+        assert_eq!(restored, session);
+        assert_eq!(restored.round_count(), 1);
+        assert_eq!(restored.remaining_range(), GuessRange::new(6, 100).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_session_invalid_test() {
+        let config = r#"{"max_attempts":10,"allow_repeats":true,"track_distances":true,"hint_mode":"HighLow"}"#;
+        let session = |secret: i32, attempts_used: u32, history: &str, won: bool| {
+            let json = format!(
+                r#"{{"secret":{},"config":{},"attempts_used":{},"history":{},"won":{},"rounds":0}}"#,
+                secret, config, attempts_used, history, won
+            );
+            serde_json::from_str::<GuessSession>(&json)
+        };
+
+        assert_eq!(session(42, 2, "[50,30]", false).unwrap(), play(42, 10, &[50, 30]));
+
+        let err = session(150, 0, "[]", false).unwrap_err();
+        assert!(err.to_string().contains("outside 0-100"));
+
+        let inconsistent = [
+            session(42, 50, "[50,30]", false),
+            session(42, 11, "[0,1,2,3,4,5,6,7,8,9,10]", false),
+            session(42, 1, "[50,30]", false),
+            session(42, 2, "[50,30]", true),
+            session(42, 2, "[42,30]", false),
+            session(42, 0, "[]", true),
+        ];
+        for result in inconsistent {
+            assert!(result.unwrap_err().to_string().contains("contradict"));
+        }
+
+        // The search bounds are recovered from the history, not read from the input.
+        let json = format!(
+            r#"{{"secret":42,"config":{},"attempts_used":0,"history":[],"won":false,"rounds":0,"lo_bound":90,"hi_bound":100}}"#,
+            config
+        );
+        let restored: GuessSession = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.remaining_range(), GuessRange::from_full_range());
+    }
+
+    #[test]
+    fn group_by_distance_test() {
+        let session = play(50, 10, &[60, 70, 55, 49]);
+        let groups = session.group_by_distance();
+
+        assert_eq!(groups.keys().copied().collect::<Vec<_>>(), [1, 5, 10, 20]);
+        assert_eq!(groups[&1], [guess(49)]);
+        assert_eq!(groups[&5], [guess(55)]);
+        assert_eq!(groups[&10], [guess(60)]);
+        assert_eq!(groups[&20], [guess(70)]);
+        assert_eq!(session.closest_cluster(2), [&guess(49), &guess(55)]);
+
+        let ties = play(50, 10, &[40, 60, 40]);
+        assert_eq!(ties.group_by_distance()[&10], [guess(40), guess(60), guess(40)]);
+        assert!(play(50, 10, &[]).group_by_distance().is_empty());
+
+        let total: usize = session.group_by_distance().values().map(Vec::len).sum();
+        assert_eq!(total, session.history().len());
+    }
+
+    #[test]
+    fn ascii_board_test() {
+        let board = play(60, 10, &[50]).format_ascii_board();
+        let expected = format!("[{}1{}X{}]", ".".repeat(50), ".".repeat(9), ".".repeat(40));
+
+        assert_eq!(board, expected);
+        assert_eq!(board.chars().nth(61), Some('X'));
+        assert_eq!(board.chars().nth(51), Some('1'));
+
+        let empty = play(0, 10, &[]).format_ascii_board();
+        assert_eq!(empty, format!("[X{}]", ".".repeat(100)));
+    }
+
+    #[test]
+    fn ascii_board_markers_test() {
+        let guesses: Vec<i32> = (1..=12).map(|i| i * 5).collect();
+        let session = play(100, 20, &guesses);
+        let cells: Vec<char> = session.format_ascii_board().chars().collect();
+
+        assert_eq!(cells.len(), 103);
+        assert_eq!((cells[0], cells[102]), ('[', ']'));
+        assert_eq!(cells[101], 'X');
+        for (i, val) in guesses.iter().enumerate() {
+            let expected = if i < 9 { char::from(b'1' + i as u8) } else { '*' };
+            assert_eq!(cells[*val as usize + 1], expected);
+        }
+        assert_eq!(cells.iter().filter(|&&c| c == '.').count(), 101 - 13);
+
+        // The secret hides a correct guess, and later attempts are drawn over earlier ones.
+        let session = play(42, 10, &[10, 10, 42]);
+        let cells: Vec<char> = session.format_ascii_board().chars().collect();
+        assert_eq!(cells[11], '2');
+        assert_eq!(cells[43], 'X');
+    }
+
+    #[test]
+    fn no_repeats_test() {
+        let config = GuessSessionConfig::default().max_attempts(3).no_repeats();
+        let mut session = GuessSession::new_with_config(guess(42), config);
+
+        assert_eq!(session.attempt(guess(10)), GuessResult::TooLow { distance: 32 });
+        for _ in 0..5 {
+            assert_eq!(session.attempt(guess(10)), GuessResult::AlreadyGuessed);
+        }
+        assert_eq!(session.attempts_used(), 1);
+        assert_eq!(session.attempts_remaining(), 2);
+        assert_eq!(session.history(), [guess(10)]);
+
+        assert_eq!(session.attempt(guess(60)), GuessResult::TooHigh { distance: 18 });
+        assert_eq!(session.attempt(guess(60)), GuessResult::AlreadyGuessed);
+        assert_eq!(session.attempt(guess(42)), GuessResult::Correct);
+        assert_eq!(session.attempt(guess(10)), GuessResult::GameOver);
+        assert_eq!(session.attempts_used(), 3);
+
+        // Repeats are allowed by default.
+        let repeated = play(42, 3, &[10, 10]);
+        assert_eq!(repeated.attempts_used(), 2);
+        assert_eq!(repeated.count_occurrences(&guess(10)), 2);
+    }
+
+    #[test]
+    fn session_config_test() {
+        let default = GuessSessionConfig::default();
+        assert_eq!(default.max_attempts, 10);
+        assert!(default.allow_repeats);
+        assert!(default.track_distances);
+        assert_eq!(default.hint_mode, HintMode::HighLow);
+        assert_eq!(GuessSession::new(guess(42), 7).config(), &default.max_attempts(7));
+
+        let config = default.max_attempts(4).track_distances(false).hint_mode(HintMode::None);
+        let mut session = GuessSession::new_with_config(guess(42), config);
+        session.attempt(guess(50));
+
+        assert_eq!(session.max_attempts(), 4);
+        assert_eq!(session.attempts_remaining(), 3);
+        assert_eq!(session.distance_history(), None);
+        assert_eq!(session.hint(), None);
+        assert_eq!(session.attempt(guess(50)), GuessResult::TooHigh { distance: 8 });
+    }
+
+    #[test]
+    fn hint_and_distance_history_test() {
+        let mut session = play(42, 10, &[50, 30]);
+        assert_eq!(session.distance_history(), Some(vec![8, 12]));
+        assert_eq!(session.hint(), Some("Too low! Try a higher number.".to_string()));
+        session.attempt(guess(42));
+        assert_eq!(session.hint(), Some("Correct! You guessed the number.".to_string()));
+
+        let config = GuessSessionConfig::default().hint_mode(HintMode::Temperature);
+        let mut session = GuessSession::new_with_config(guess(42), config);
+        assert_eq!(session.hint(), None);
+        session.attempt(guess(90));
+        assert_eq!(session.hint(), Some("freezing cold".to_string()));
+        session.attempt(guess(40));
+        assert_eq!(session.hint(), Some("hot".to_string()));
+        assert_eq!(play(42, 10, &[]).distance_history(), Some(vec![]));
+    }
+
+    #[test]
+    fn monotonic_improvement_streak_test() {
+        let converging = play(50, 10, &[0, 20, 35, 44, 48, 50]);
+        assert_eq!(converging.monotonic_improvement_streak(), 6);
+        assert_eq!(converging.longest_improvement_streak(), 6);
+        assert!(converging.is_monotonically_approaching());
+
+        // A single divergence after the third guess splits the history into runs of 3 and 2.
+        let diverged = play(50, 10, &[0, 20, 35, 10, 40]);
+        assert_eq!(diverged.monotonic_improvement_streak(), 2);
+        assert_eq!(diverged.longest_improvement_streak(), 3);
+        assert!(!diverged.is_monotonically_approaching());
+
+        let diverging = play(50, 10, &[49, 47, 60, 30, 100]);
+        assert_eq!(diverging.monotonic_improvement_streak(), 1);
+        assert_eq!(diverging.longest_improvement_streak(), 1);
+        assert!(!diverging.is_monotonically_approaching());
+
+        for session in [converging, diverged, diverging] {
+            assert_eq!(session.monotonic_improvement_streak(), session.approaching_streak());
+            assert_eq!(
+                session.is_monotonically_approaching(),
+                session.monotonic_improvement_streak() as usize == session.history().len()
+            );
+        }
+    }
+
+    #[test]
+    fn remaining_range_test() {
+        let session = play(60, 10, &[50, 75]);
+        assert_eq!(session.remaining_range(), GuessRange::new(51, 74).unwrap());
+
+        let session = play(60, 10, &[50, 40, 90, 75]);
+        assert_eq!(session.remaining_range(), GuessRange::new(51, 74).unwrap());
+
+        let mut session = play(60, 10, &[50, 60]);
+        assert_eq!(session.remaining_range(), GuessRange::new(51, 100).unwrap());
+        session.reset_with_secret(guess(10));
+        assert_eq!(session.remaining_range(), GuessRange::from_full_range());
+
+        for secret in 0..=100 {
+            let mut session = GuessSession::new(guess(secret), 20);
+
+            for val in [50, 25, 75, 12, 88, 37, 63, 0, 100, secret] {
+                session.attempt(guess(val));
+
+                assert!(session.remaining_range().contains_guess(&guess(secret)));
+            }
+
+            let restored = GuessSession::deserialize_from_bytes(&session.serialize_to_bytes().unwrap()).unwrap();
+            assert_eq!(restored.remaining_range(), session.remaining_range());
+        }
+    }
+
+    #[test]
+    fn replay_as_json_test() {
+        assert_eq!(GuessSession::new(guess(42), 5).replay_as_json(), "[]");
+
+        let session = play(42, 4, &[50, 30, 42]);
+        let json: serde_json::Value = serde_json::from_str(&session.replay_as_json()).unwrap();
+        let steps = json.as_array().unwrap();
+
+        assert_eq!(steps.len(), session.history().len());
+        for (i, (step, g)) in steps.iter().zip(session.history()).enumerate() {
+            assert_eq!(step["attempt"], i + 1);
+            assert_eq!(step["guess"], g.get());
+            assert_eq!(step["distance"], g.abs_diff(&guess(42)));
+        }
+        assert_eq!(steps[0]["result"], "Too high by 8.");
+        assert_eq!(steps[2]["result"], "Correct! You guessed the number.");
+
+        let session = play(42, 2, &[50, 30]);
+        let json: serde_json::Value = serde_json::from_str(&session.replay_as_json()).unwrap();
+
+        assert_eq!(json[0]["distance"], 8);
+        assert_eq!(json[1]["result"], "Game over! No attempts left.");
+        assert!(json[1]["distance"].is_null());
+    }
+
+    #[test]
+    fn new_with_rng_test() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        for seed in 0..20 {
+            let mut a = GuessSession::new_with_rng(&mut StdRng::seed_from_u64(seed), 5);
+            let mut b = GuessSession::new_with_rng(&mut StdRng::seed_from_u64(seed), 5);
+
+            assert_eq!(a.secret(), crate::gen_random_seeded(seed));
+            assert_eq!(a, b);
+
+            for val in [50, 25, 75, 12, 88] {
+                assert_eq!(a.attempt(guess(val)), b.attempt(guess(val)));
+            }
+            assert_eq!(a, b);
+
+            let (mut rng_a, mut rng_b) = (StdRng::seed_from_u64(seed + 100), StdRng::seed_from_u64(seed + 100));
+            a.reset_with_rng(&mut rng_a);
+            b.reset_with_rng(&mut rng_b);
+
+            assert_eq!(a.secret(), crate::gen_random_seeded(seed + 100));
+            assert_eq!(a, b);
+        }
+
+        let config = GuessSessionConfig::default().max_attempts(3).no_repeats();
+        assert_eq!(
+            GuessSession::new_with_secret_and_config(guess(42), config),
+            GuessSession::new_with_config(guess(42), config)
+        );
+    }
+
+    #[test]
+    fn coverage_test() {
+        let empty = GuessSession::new(guess(42), 10);
+        assert_eq!(empty.unique_guess_count(), 0);
+        assert_eq!(empty.coverage_percentage(), 0.0);
+
+        let repeated = play(42, 10, &[7, 7, 7, 7, 7]);
+        assert_eq!(repeated.unique_guess_count(), 1);
+        assert_eq!(repeated.coverage_percentage(), 100.0 / 101.0);
+
+        let mut session = GuessSession::new(Guess::MAX, 300);
+        for val in 0..=100 {
+            session.attempt(guess(val));
+            session.attempt(guess(val / 2));
+
+            let unique = val as usize + 1;
+            assert_eq!(session.unique_guess_count(), unique);
+            assert!((session.coverage_percentage() - unique as f64 * 100.0 / 101.0).abs() < 1e-9);
+        }
+
+        assert_eq!(session.coverage_percentage(), 100.0);
+    }
+
+    #[test]
+    fn optimal_guess_sequence_test() {
+        assert_eq!(GuessSession::optimal_guesses_count(&Guess::MIDPOINT), 1);
+        assert_eq!(GuessSession::optimal_guess_sequence(&Guess::MIDPOINT), [Guess::MIDPOINT]);
+
+        let mut counts = [0; 8];
+        for secret in (0..=100).map(guess) {
+            let sequence = GuessSession::optimal_guess_sequence(&secret);
+
+            assert!(sequence.len() <= 7);
+            assert_eq!(sequence.last(), Some(&secret));
+            assert_eq!(GuessSession::optimal_guesses_count(&secret), sequence.len());
+
+            let (mut lo, mut hi) = (0, 100);
+            for g in &sequence {
+                assert_eq!(g.get(), (lo + hi) / 2);
+
+                match g.compare_to_target(&secret) {
+                    crate::hint::GuessHint::TooHigh => hi = g.get() - 1,
+                    crate::hint::GuessHint::TooLow => lo = g.get() + 1,
+                    crate::hint::GuessHint::Correct => {}
+                }
+            }
+
+            // Playing the sequence in a session wins on its last guess.
+            let session = play(secret.get(), 7, &sequence.iter().map(|g| g.get()).collect::<Vec<_>>());
+            assert!(session.is_won());
+            assert_eq!(session.attempts_used() as usize, sequence.len());
+
+            counts[sequence.len()] += 1;
+        }
+
+        // A binary search tree over 101 values has 1, 2, 4, ... nodes at each depth.
+        assert_eq!(counts, [0, 1, 2, 4, 8, 16, 32, 38]);
+    }
+
+    #[test]
+    fn solvability_test() {
+        assert_eq!(GuessSession::minimum_guaranteed_attempts(), 7);
+        assert_eq!(GuessSession::worst_case_sequence_length(), 7);
+
+        let longest = (0..=100).map(|val| GuessSession::optimal_guesses_count(&guess(val))).max();
+        assert_eq!(longest, Some(GuessSession::worst_case_sequence_length() as usize));
+
+        assert!(GuessSession::is_solvable_in_n_attempts(7));
+        assert!(GuessSession::is_solvable_in_n_attempts(u32::MAX));
+        assert!(!GuessSession::is_solvable_in_n_attempts(6));
+        assert!(!GuessSession::is_solvable_in_n_attempts(0));
+    }
+
+    #[test]
+    fn score_by_formula_test() {
+        use crate::scoring::{distance_penalty_score, linear_score};
+
+        let first_guess = play(42, 5, &[42]);
+        let third_guess = play(42, 5, &[50, 30, 42]);
+        let lost = play(42, 2, &[50, 30]);
+        let ongoing = play(42, 5, &[50]);
+
+        assert_eq!(first_guess.score_by_formula(&linear_score(5)), Some(100.0));
+        assert_eq!(third_guess.score_by_formula(&linear_score(5)), Some(60.0));
+        assert_eq!(lost.score_by_formula(&linear_score(2)), Some(0.0));
+        assert_eq!(ongoing.score_by_formula(&linear_score(5)), None);
+        assert_eq!(first_guess.score_by_formula(&distance_penalty_score(5)), Some(100.0));
+
+        let seen = |session: &GuessSession| {
+            let args = core::cell::Cell::new(None);
+            session.score_by_formula(&|attempts, distance| {
+                args.set(Some((attempts, distance)));
+                0.0
+            });
+            args.get()
+        };
+        assert_eq!(seen(&third_guess), Some((3, Some(0))));
+        assert_eq!(seen(&lost), Some((2, None)));
+        assert_eq!(seen(&ongoing), None);
+
+        let custom = |attempts: u32, distance: Option<u32>| match distance {
+            Some(_) => 1000.0 / attempts as f64,
+            None => -1.0,
+        };
+        assert_eq!(third_guess.score_by_formula(&custom).map(|score| score.round()), Some(333.0));
+        assert_eq!(lost.score_by_formula(&custom), Some(-1.0));
+    }
+
+    #[test]
+    fn difficulty_score_test() {
+        let first_guess = play(42, 10, &[42]);
+        assert_eq!(first_guess.difficulty_score(), Some(1.0));
+        assert_eq!(first_guess.normalized_difficulty_score(), Some(0.0));
+        assert_eq!(first_guess.difficulty_grade(), Some("S"));
+
+        let third_guess = play(42, 10, &[52, 32, 42]);
+        let three = 3.0 * (1.0 + (10.0 + 10.0) / 3.0 / 100.0);
+        assert!((third_guess.difficulty_score().unwrap() - three).abs() < 1e-9);
+        assert_eq!(third_guess.difficulty_grade(), Some("A"));
+
+        let binary_search = play(30, 10, &[50, 24, 37, 30]);
+        assert!((binary_search.difficulty_score().unwrap() - 4.0 * (1.0 + (20.0 + 6.0 + 7.0) / 4.0 / 100.0)).abs() < 1e-9);
+        assert_eq!(binary_search.difficulty_grade(), Some("B"));
+
+        let slow = play(42, 10, &[0, 10, 20, 30, 35, 38, 40, 41, 42]);
+        assert_eq!(slow.difficulty_grade(), Some("D"));
+        assert!(slow.difficulty_score() > third_guess.difficulty_score());
+
+        let lost = play(42, 3, &[40, 41, 43]);
+        let won_same_attempts = play(42, 3, &[40, 41, 42]);
+        assert_eq!(lost.difficulty_score(), Some(2.0 * 3.0 * (1.0 + 4.0 / 3.0 / 100.0)));
+        assert!(lost.difficulty_score() > won_same_attempts.difficulty_score());
+        assert_eq!(lost.difficulty_grade(), Some("F"));
+
+        let worst = play(100, 3, &[0, 0, 0]);
+        assert_eq!(worst.difficulty_score(), Some(12.0));
+        assert_eq!(worst.normalized_difficulty_score(), Some(1.0));
+
+        for session in [&first_guess, &third_guess, &binary_search, &slow, &lost, &won_same_attempts, &worst] {
+            let normalized = session.normalized_difficulty_score().unwrap();
+            assert!((0.0..=1.0).contains(&normalized));
+        }
+        assert!(third_guess.normalized_difficulty_score() < slow.normalized_difficulty_score());
+
+        let no_attempts = GuessSession::new(guess(42), 0);
+        assert!(no_attempts.is_lost());
+        assert_eq!(no_attempts.difficulty_score(), Some(4.0));
+        assert_eq!(no_attempts.normalized_difficulty_score(), Some(1.0));
+        assert_eq!(no_attempts.difficulty_grade(), Some("F"));
+
+        let ongoing = play(42, 10, &[50]);
+        assert_eq!(ongoing.difficulty_score(), None);
+        assert_eq!(ongoing.normalized_difficulty_score(), None);
+        assert_eq!(ongoing.difficulty_grade(), None);
+    }
+}
@@ -0,0 +1,307 @@
+//! Statistics over collections of guesses.
+//! 
+//! # Example
+//! ```
+//! use guessing_utils::Guess;
+//! use guessing_utils::stats::{self, GuessStats};
+//! 
+//! let guesses: Vec<Guess> = [50, 25, 37, 43, 40].iter().map(|&val| Guess::new(val).unwrap()).collect();
+//! let summary = GuessStats::from_slice(&guesses).unwrap();
+//! 
+//! assert_eq!(summary.mean(), 39.0);
+//! assert_eq!(stats::median(&guesses), Some(40.0));
+//! ```
+
+use crate::{float, Guess, GUESS_RANGE_SIZE};
+
+/// Precomputed summary statistics of a non-empty collection of guesses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GuessStats {
+    count: usize,
+    mean: f64,
+    variance: f64,
+    min: Guess,
+    max: Guess,
+}
+
+impl GuessStats {
+    /// Computes the statistics of the guesses, returning `None` for an empty slice.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use guessing_utils::stats::GuessStats;
+    /// 
+    /// assert!(GuessStats::from_slice(&[Guess::MIN, Guess::MAX]).is_some());
+    /// assert!(GuessStats::from_slice(&[]).is_none());
+    /// ```
+    pub fn from_slice(guesses: &[Guess]) -> Option<GuessStats> {
+        let min = *guesses.iter().min()?;
+        let max = *guesses.iter().max()?;
+
+        let count = guesses.len();
+        let mean = guesses.iter().map(|g| g.val as f64).sum::<f64>() / count as f64;
+        let variance = guesses.iter().map(|g| g.val as f64 - mean).map(|d| d * d).sum::<f64>() / count as f64;
+
+        Some(GuessStats { count, mean, variance, min, max })
+    }
+
+    /// Gets the number of guesses the statistics were computed from.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Gets the arithmetic mean of the guesses.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Gets the population variance of the guesses.
+    pub fn variance(&self) -> f64 {
+        self.variance
+    }
+
+    /// Gets the population standard deviation of the guesses.
+    pub fn std_dev(&self) -> f64 {
+        float::sqrt(self.variance)
+    }
+
+    /// Gets the smallest guess.
+    pub fn min(&self) -> Guess {
+        self.min
+    }
+
+    /// Gets the largest guess.
+    pub fn max(&self) -> Guess {
+        self.max
+    }
+}
+
+/// Computes the median of the guesses, averaging the two middle values for an even count.
+/// Returns `None` for an empty slice.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::stats::median;
+/// 
+/// assert_eq!(median(&[Guess::MIN, Guess::new(5).unwrap()]), Some(2.5));
+/// ```
+pub fn median(guesses: &[Guess]) -> Option<f64> {
+    if guesses.is_empty() {
+        return None;
+    }
+
+    let mut sorted = guesses.to_vec();
+    sorted.sort_unstable();
+
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        Some((sorted[mid - 1].val + sorted[mid].val) as f64 / 2.0)
+    } else {
+        Some(sorted[mid].val as f64)
+    }
+}
+
+/// Computes the `pct`-th percentile of the guesses on a `[0.0, 100.0]` scale,
+/// linearly interpolating between the closest ranks.
+/// Returns `None` for an empty slice or a percentage outside `[0.0, 100.0]`.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::stats::percentile;
+/// 
+/// let guesses = [Guess::MIN, Guess::MIDPOINT, Guess::MAX];
+/// 
+/// assert_eq!(percentile(&guesses, 50.0), Some(50.0));
+/// assert_eq!(percentile(&guesses, 25.0), Some(25.0));
+/// ```
+pub fn percentile(guesses: &[Guess], pct: f64) -> Option<f64> {
+    if guesses.is_empty() || !(0.0..=100.0).contains(&pct) {
+        return None;
+    }
+
+    let mut sorted = guesses.to_vec();
+    sorted.sort_unstable();
+
+    let rank = pct / 100.0 * (sorted.len() - 1) as f64;
+    let lower = sorted[float::floor(rank) as usize].val as f64;
+    let upper = sorted[float::ceil(rank) as usize].val as f64;
+
+    Some(lower + (upper - lower) * (rank - float::floor(rank)))
+}
+
+/// Computes the quartiles of the guesses as `(Q1, Q2, Q3)`, the 25th, 50th and 75th percentiles.
+/// Returns `None` for an empty slice.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::stats::quartiles;
+/// 
+/// let guesses: Vec<Guess> = (0..=100).map(|val| Guess::new(val).unwrap()).collect();
+/// 
+/// assert_eq!(quartiles(&guesses), Some((25.0, 50.0, 75.0)));
+/// ```
+pub fn quartiles(guesses: &[Guess]) -> Option<(f64, f64, f64)> {
+    Some((percentile(guesses, 25.0)?, percentile(guesses, 50.0)?, percentile(guesses, 75.0)?))
+}
+
+/// Computes the interquartile range of the guesses, `Q3 - Q1`.
+/// Returns `None` for an empty slice.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::stats::interquartile_range;
+/// 
+/// let guesses: Vec<Guess> = (0..=100).map(|val| Guess::new(val).unwrap()).collect();
+/// 
+/// assert_eq!(interquartile_range(&guesses), Some(50.0));
+/// ```
+pub fn interquartile_range(guesses: &[Guess]) -> Option<f64> {
+    let (q1, _, q3) = quartiles(guesses)?;
+
+    Some(q3 - q1)
+}
+
+/// Finds the most common guess, preferring the smallest value on ties.
+/// Returns `None` for an empty slice.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::stats::mode;
+/// 
+/// assert_eq!(mode(&[Guess::MAX, Guess::MIN, Guess::MAX]), Some(Guess::MAX));
+/// ```
+pub fn mode(guesses: &[Guess]) -> Option<Guess> {
+    if guesses.is_empty() {
+        return None;
+    }
+
+    let counts = histogram(guesses);
+    let mut best = 0;
+    for (val, &count) in counts.iter().enumerate() {
+        if count > counts[best] {
+            best = val;
+        }
+    }
+
+    Some(Guess { val: best as i32 })
+}
+
+/// Counts the occurrences of every value, indexed by the value.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::stats::histogram;
+/// 
+/// let counts = histogram(&[Guess::MAX, Guess::MIN, Guess::MAX]);
+/// 
+/// assert_eq!(counts[100], 2);
+/// assert_eq!(counts[0], 1);
+/// ```
+pub fn histogram(guesses: &[Guess]) -> [u32; GUESS_RANGE_SIZE] {
+    let mut counts = [0; GUESS_RANGE_SIZE];
+    for g in guesses {
+        counts[g.val as usize] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guesses(values: &[i32]) -> Vec<Guess> {
+        values.iter().map(|&val| Guess::new(val).unwrap()).collect()
+    }
+
+    #[test]
+    fn guess_stats_test() {
+        // Mean 5, squared deviations 9 + 1 + 1 + 1 + 0 + 0 + 4 + 16 = 32, variance 32 / 8 = 4.
+        let data = guesses(&[2, 4, 4, 4, 5, 5, 7, 9]);
+        let stats = GuessStats::from_slice(&data).unwrap();
+
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.mean(), 5.0);
+        assert_eq!(stats.variance(), 4.0);
+        assert_eq!(stats.std_dev(), 2.0);
+        assert_eq!(stats.min().get(), 2);
+        assert_eq!(stats.max().get(), 9);
+        assert_eq!(GuessStats::from_slice(&[]), None);
+    }
+
+    #[test]
+    fn single_element_test() {
+        let data = guesses(&[42]);
+        let stats = GuessStats::from_slice(&data).unwrap();
+
+        assert_eq!(stats.mean(), 42.0);
+        assert_eq!(stats.variance(), 0.0);
+        assert_eq!(stats.std_dev(), 0.0);
+        assert_eq!(stats.min(), stats.max());
+        assert_eq!(median(&data), Some(42.0));
+        assert_eq!(mode(&data), Some(data[0]));
+    }
+
+    #[test]
+    fn median_mode_test() {
+        assert_eq!(median(&guesses(&[9, 2, 7, 4, 5])), Some(5.0));
+        assert_eq!(median(&guesses(&[9, 2, 7, 4])), Some(5.5));
+        assert_eq!(median(&[]), None);
+
+        assert_eq!(mode(&guesses(&[3, 8, 8, 1, 8, 3])), Some(Guess::new(8).unwrap()));
+        assert_eq!(mode(&guesses(&[70, 30, 70, 30])), Some(Guess::new(30).unwrap()));
+        assert_eq!(mode(&[]), None);
+    }
+
+    #[test]
+    fn histogram_test() {
+        let counts = histogram(&guesses(&[0, 100, 50, 50, 50]));
+
+        assert_eq!(counts[0], 1);
+        assert_eq!(counts[50], 3);
+        assert_eq!(counts[100], 1);
+        assert_eq!(counts.iter().sum::<u32>(), 5);
+        assert_eq!(histogram(&[]), [0; 101]);
+    }
+
+    #[test]
+    fn percentile_test() {
+        let data = guesses(&[0, 50, 100]);
+
+        assert_eq!(percentile(&data, 50.0), Some(50.0));
+        assert_eq!(percentile(&data, 0.0), Some(0.0));
+        assert_eq!(percentile(&data, 100.0), Some(100.0));
+        assert_eq!(percentile(&data, 75.0), Some(75.0));
+        assert_eq!(percentile(&guesses(&[10, 20]), 30.0), Some(13.0));
+        assert_eq!(percentile(&guesses(&[7]), 90.0), Some(7.0));
+        assert_eq!(percentile(&data, -1.0), None);
+        assert_eq!(percentile(&data, 100.5), None);
+        assert_eq!(percentile(&data, f64::NAN), None);
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[test]
+    fn percentile_bounds_test() {
+        let data = guesses(&[42, 7, 93, 15, 61, 28]);
+        let stats = GuessStats::from_slice(&data).unwrap();
+
+        assert_eq!(percentile(&data, 0.0), Some(stats.min().get() as f64));
+        assert_eq!(percentile(&data, 100.0), Some(stats.max().get() as f64));
+        assert_eq!(percentile(&data, 50.0), median(&data));
+    }
+
+    #[test]
+    fn quartiles_test() {
+        let data = guesses(&[1, 3, 5, 7, 9, 11, 13, 15, 17]);
+        let (q1, q2, q3) = quartiles(&data).unwrap();
+
+        assert_eq!((q1, q2, q3), (5.0, 9.0, 13.0));
+        assert_eq!(Some(q1), percentile(&data, 25.0));
+        assert_eq!(Some(q2), percentile(&data, 50.0));
+        assert_eq!(Some(q3), percentile(&data, 75.0));
+        assert!(q1 <= q2);
+        assert!(q2 <= q3);
+        assert_eq!(interquartile_range(&data), Some(8.0));
+        assert_eq!(quartiles(&[]), None);
+        assert_eq!(interquartile_range(&[]), None);
+    }
+}
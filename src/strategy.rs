@@ -0,0 +1,253 @@
+//! Strategies turning the state of a session into hints for the player.
+//! 
+//! # Example
+//! ```
+//! use guessing_utils::{Guess, GuessSession};
+//! use guessing_utils::strategy::{HintStrategy, SimpleHintStrategy};
+//! 
+//! let mut session = GuessSession::new(Guess::new(42).unwrap(), 10);
+//! session.attempt(Guess::new(70).unwrap());
+//! 
+//! assert_eq!(SimpleHintStrategy.generate_hint(&session), "Too high!");
+//! ```
+
+use crate::hint::GuessHint;
+use crate::{Guess, GuessSession, GUESS_RANGE_MAX, GUESS_RANGE_MIN};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Generates the hint shown to the player after a guess,
+/// allowing custom hint vocabularies without touching how the game is stored.
+pub trait HintStrategy: Send + Sync {
+    /// Generates a hint about the most recent guess of the session.
+    fn generate_hint(&self, session: &GuessSession) -> String;
+}
+
+/// Tells whether the last guess was too high or too low.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessSession};
+/// use guessing_utils::strategy::{HintStrategy, SimpleHintStrategy};
+/// 
+/// let mut session = GuessSession::new(Guess::new(42).unwrap(), 10);
+/// assert_eq!(SimpleHintStrategy.generate_hint(&session), "Make a guess!");
+/// 
+/// session.attempt(Guess::new(10).unwrap());
+/// assert_eq!(SimpleHintStrategy.generate_hint(&session), "Too low!");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SimpleHintStrategy;
+
+impl HintStrategy for SimpleHintStrategy {
+    fn generate_hint(&self, session: &GuessSession) -> String {
+        let hint = match session.last_guess() {
+            Some(guess) => match guess.compare_to_target(&session.secret()) {
+                GuessHint::Correct => "Correct!",
+                GuessHint::TooHigh => "Too high!",
+                GuessHint::TooLow => "Too low!",
+            },
+            None => "Make a guess!",
+        };
+
+        hint.to_string()
+    }
+}
+
+/// Tells how far off the last guess was, along with the attempts used so far.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessSession};
+/// use guessing_utils::strategy::{HintStrategy, VerboseHintStrategy};
+/// 
+/// let mut session = GuessSession::new(Guess::new(50).unwrap(), 10);
+/// for val in [10, 80, 70] {
+///     session.attempt(Guess::new(val).unwrap());
+/// }
+/// 
+/// assert_eq!(VerboseHintStrategy.generate_hint(&session), "Attempt 3/10 — your guess of 70 was too high by 20");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct VerboseHintStrategy;
+
+impl HintStrategy for VerboseHintStrategy {
+    fn generate_hint(&self, session: &GuessSession) -> String {
+        let guess = match session.last_guess() {
+            Some(guess) => guess,
+            None => return format!("No guesses yet — {} attempts remaining", session.attempts_remaining()),
+        };
+
+        let secret = session.secret();
+        let outcome = match guess.compare_to_target(&secret) {
+            GuessHint::Correct => "correct".to_string(),
+            GuessHint::TooHigh => format!("too high by {}", guess.abs_diff(&secret)),
+            GuessHint::TooLow => format!("too low by {}", guess.abs_diff(&secret)),
+        };
+
+        format!(
+            "Attempt {}/{} — your guess of {} was {}",
+            session.attempts_used(),
+            session.max_attempts(),
+            guess,
+            outcome
+        )
+    }
+}
+
+/// Suggests the guess halving the range the secret can still be in,
+/// finding any secret in at most `7` guesses.
+/// # Example
+/// ```
+/// use guessing_utils::{Guess, GuessSession};
+/// use guessing_utils::strategy::{BinarySearchStrategy, HintStrategy};
+/// 
+/// let strategy = BinarySearchStrategy::new();
+/// let mut session = GuessSession::new(Guess::new(42).unwrap(), 10);
+/// assert_eq!(strategy.suggest_next(&session), Guess::new(50).unwrap());
+/// 
+/// session.attempt(Guess::new(50).unwrap());
+/// assert_eq!(strategy.suggest_next(&session), Guess::new(24).unwrap());
+/// assert_eq!(strategy.generate_hint(&session), "Too high! Try 24 next.");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BinarySearchStrategy {
+    lo: i32,
+    hi: i32,
+}
+
+impl BinarySearchStrategy {
+    /// Creates a strategy searching the whole range of guesses.
+    pub fn new() -> BinarySearchStrategy {
+        BinarySearchStrategy { lo: GUESS_RANGE_MIN, hi: GUESS_RANGE_MAX }
+    }
+
+    /// Suggests the midpoint of the range the secret can still be in, given the feedback on past guesses.
+    /// Once the secret was guessed, it is always the suggestion.
+    pub fn suggest_next(&self, session: &GuessSession) -> Guess {
+        let (lo, hi) = self.narrow(session);
+
+        Guess::new_clamped(lo + (hi - lo) / 2)
+    }
+
+    /// Narrows the bounds down to the values consistent with every guess of the session.
+    fn narrow(&self, session: &GuessSession) -> (i32, i32) {
+        let secret = session.secret();
+
+        session.history().iter().fold((self.lo, self.hi), |(lo, hi), guess| {
+            match guess.compare_to_target(&secret) {
+                GuessHint::Correct => (guess.get(), guess.get()),
+                GuessHint::TooHigh => (lo, hi.min(guess.get() - 1)),
+                GuessHint::TooLow => (lo.max(guess.get() + 1), hi),
+            }
+        })
+    }
+}
+
+impl Default for BinarySearchStrategy {
+    fn default() -> Self {
+        BinarySearchStrategy::new()
+    }
+}
+
+impl HintStrategy for BinarySearchStrategy {
+    fn generate_hint(&self, session: &GuessSession) -> String {
+        let feedback = SimpleHintStrategy.generate_hint(session);
+
+        if session.is_won() {
+            feedback
+        } else if session.last_guess().is_none() {
+            format!("Try {} first.", self.suggest_next(session))
+        } else {
+            format!("{} Try {} next.", feedback, self.suggest_next(session))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(secret: i32, max_attempts: u32, guesses: &[i32]) -> GuessSession {
+        let mut session = GuessSession::new(Guess::new(secret).unwrap(), max_attempts);
+        for &val in guesses {
+            session.attempt(Guess::new(val).unwrap());
+        }
+        session
+    }
+
+    #[test]
+    fn simple_hint_strategy_test() {
+        let strategy = SimpleHintStrategy;
+
+        assert_eq!(strategy.generate_hint(&session(42, 10, &[70])), "Too high!");
+        assert_eq!(strategy.generate_hint(&session(42, 10, &[70, 10])), "Too low!");
+        assert_eq!(strategy.generate_hint(&session(42, 10, &[70, 42])), "Correct!");
+        assert!(!strategy.generate_hint(&session(42, 10, &[])).is_empty());
+    }
+
+    #[test]
+    fn verbose_hint_strategy_test() {
+        let strategy = VerboseHintStrategy;
+
+        assert_eq!(
+            strategy.generate_hint(&session(50, 10, &[10, 80, 70])),
+            "Attempt 3/10 — your guess of 70 was too high by 20"
+        );
+        assert_eq!(
+            strategy.generate_hint(&session(50, 5, &[45])),
+            "Attempt 1/5 — your guess of 45 was too low by 5"
+        );
+        assert_eq!(
+            strategy.generate_hint(&session(50, 5, &[45, 50])),
+            "Attempt 2/5 — your guess of 50 was correct"
+        );
+        assert_eq!(strategy.generate_hint(&session(50, 5, &[])), "No guesses yet — 5 attempts remaining");
+    }
+
+    #[test]
+    fn boxed_strategies_test() {
+        let strategies: Vec<Box<dyn HintStrategy>> = vec![Box::new(SimpleHintStrategy), Box::new(VerboseHintStrategy)];
+        let session = session(42, 10, &[70]);
+
+        for strategy in &strategies {
+            assert!(!strategy.generate_hint(&session).is_empty());
+        }
+    }
+
+    #[test]
+    fn binary_search_first_suggestion_test() {
+        let strategy = BinarySearchStrategy::new();
+
+        for secret in 0..=100 {
+            assert_eq!(strategy.suggest_next(&session(secret, 10, &[])), Guess::new(50).unwrap());
+        }
+        assert_eq!(BinarySearchStrategy::default(), strategy);
+    }
+
+    #[test]
+    fn binary_search_simulation_test() {
+        let strategy = BinarySearchStrategy::new();
+
+        for secret in 0..=100 {
+            let mut session = session(secret, 7, &[]);
+
+            while !session.is_over() {
+                session.attempt(strategy.suggest_next(&session));
+            }
+
+            assert!(session.is_won(), "secret {} not found in 7 guesses", secret);
+            assert!(session.attempts_used() <= 7);
+            assert_eq!(strategy.suggest_next(&session), session.secret());
+        }
+    }
+
+    #[test]
+    fn binary_search_hint_test() {
+        let strategy = BinarySearchStrategy::new();
+
+        assert_eq!(strategy.generate_hint(&session(42, 10, &[])), "Try 50 first.");
+        assert_eq!(strategy.generate_hint(&session(42, 10, &[50])), "Too high! Try 24 next.");
+        assert_eq!(strategy.generate_hint(&session(42, 10, &[50, 24])), "Too low! Try 37 next.");
+        assert_eq!(strategy.generate_hint(&session(42, 10, &[50, 42])), "Correct!");
+    }
+}
@@ -0,0 +1,288 @@
+//! Temperature hints, describing how close a guess is to the target.
+//! 
+//! # Example
+//! ```
+//! use guessing_utils::Guess;
+//! use guessing_utils::temperature::TemperatureHint;
+//! 
+//! let secret = Guess::new(42).unwrap();
+//! let guess = Guess::new(45).unwrap();
+//! 
+//! assert_eq!(guess.temperature_hint(&secret), TemperatureHint::Hot);
+//! println!("You're getting {}!", guess.temperature_hint(&secret));
+//! ```
+
+use crate::Guess;
+
+use core::fmt;
+
+/// The largest distance from the target still considered `Hot`.
+pub const HOT_THRESHOLD: u32 = 5;
+
+/// The largest distance from the target still considered `Warm`.
+pub const WARM_THRESHOLD: u32 = 15;
+
+/// The largest distance from the target still considered `Cold`.
+/// Anything further away is `Freezing`.
+pub const COLD_THRESHOLD: u32 = 30;
+
+/// Custom thresholds for temperature hints, for games wanting a different feel than the defaults.
+/// The builder methods do not validate the thresholds, use `is_valid()` or `TemperatureConfig::new` to check them.
+/// # Example
+/// ```
+/// use guessing_utils::Guess;
+/// use guessing_utils::temperature::{TemperatureConfig, TemperatureHint};
+/// 
+/// let config = TemperatureConfig::default().hot(2).warm(5).cold(10);
+/// let secret = Guess::new(42).unwrap();
+/// 
+/// assert!(config.is_valid());
+/// assert_eq!(Guess::new(50).unwrap().temperature_hint_with_config(&secret, &config), TemperatureHint::Cold);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TemperatureConfig {
+    /// The largest distance from the target still considered `Hot`.
+    pub hot_threshold: u32,
+    /// The largest distance from the target still considered `Warm`.
+    pub warm_threshold: u32,
+    /// The largest distance from the target still considered `Cold`.
+    pub cold_threshold: u32,
+}
+
+impl TemperatureConfig {
+    /// Creates a new config, returning `None` unless `0 < hot < warm < cold`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::temperature::TemperatureConfig;
+    /// 
+    /// assert!(TemperatureConfig::new(1, 2, 3).is_some());
+    /// assert!(TemperatureConfig::new(10, 5, 30).is_none());
+    /// ```
+    pub fn new(hot_threshold: u32, warm_threshold: u32, cold_threshold: u32) -> Option<TemperatureConfig> {
+        let config = TemperatureConfig { hot_threshold, warm_threshold, cold_threshold };
+
+        config.is_valid().then_some(config)
+    }
+
+    /// Sets the largest distance still considered `Hot`.
+    pub fn hot(mut self, t: u32) -> Self {
+        self.hot_threshold = t;
+        self
+    }
+
+    /// Sets the largest distance still considered `Warm`.
+    pub fn warm(mut self, t: u32) -> Self {
+        self.warm_threshold = t;
+        self
+    }
+
+    /// Sets the largest distance still considered `Cold`.
+    pub fn cold(mut self, t: u32) -> Self {
+        self.cold_threshold = t;
+        self
+    }
+
+    /// Checks if the thresholds are positive and strictly increasing.
+    /// # Example
+    /// ```
+    /// use guessing_utils::temperature::TemperatureConfig;
+    /// 
+    /// assert!(TemperatureConfig::default().is_valid());
+    /// assert!(!TemperatureConfig::default().hot(20).is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        0 < self.hot_threshold && self.hot_threshold < self.warm_threshold && self.warm_threshold < self.cold_threshold
+    }
+}
+
+impl Default for TemperatureConfig {
+    fn default() -> Self {
+        TemperatureConfig {
+            hot_threshold: HOT_THRESHOLD,
+            warm_threshold: WARM_THRESHOLD,
+            cold_threshold: COLD_THRESHOLD,
+        }
+    }
+}
+
+/// Describes how close a guess is to the target, from `Freezing` (far away) to `Burning` (exact).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemperatureHint {
+    /// Further than `COLD_THRESHOLD` from the target.
+    Freezing,
+    /// At most `COLD_THRESHOLD` from the target.
+    Cold,
+    /// At most `WARM_THRESHOLD` from the target.
+    Warm,
+    /// At most `HOT_THRESHOLD` from the target.
+    Hot,
+    /// Exactly the target.
+    Burning,
+}
+
+impl TemperatureHint {
+    /// Gets the temperature for the provided distance from the target, using the default thresholds.
+    /// # Example
+    /// ```
+    /// use guessing_utils::temperature::TemperatureHint;
+    /// 
+    /// assert_eq!(TemperatureHint::from_distance(0), TemperatureHint::Burning);
+    /// assert_eq!(TemperatureHint::from_distance(20), TemperatureHint::Cold);
+    /// ```
+    pub fn from_distance(distance: u32) -> TemperatureHint {
+        TemperatureHint::from_distance_with_config(distance, &TemperatureConfig::default())
+    }
+
+    /// Gets the temperature for the provided distance from the target, using custom thresholds.
+    /// # Example
+    /// ```
+    /// use guessing_utils::temperature::{TemperatureConfig, TemperatureHint};
+    /// 
+    /// let config = TemperatureConfig::new(1, 2, 3).unwrap();
+    /// 
+    /// assert_eq!(TemperatureHint::from_distance_with_config(20, &config), TemperatureHint::Freezing);
+    /// ```
+    pub fn from_distance_with_config(distance: u32, config: &TemperatureConfig) -> TemperatureHint {
+        match distance {
+            0 => TemperatureHint::Burning,
+            d if d <= config.hot_threshold => TemperatureHint::Hot,
+            d if d <= config.warm_threshold => TemperatureHint::Warm,
+            d if d <= config.cold_threshold => TemperatureHint::Cold,
+            _ => TemperatureHint::Freezing,
+        }
+    }
+
+    /// Gets an emoji representing the temperature.
+    /// # Example
+    /// ```
+    /// use guessing_utils::temperature::TemperatureHint;
+    /// 
+    /// assert_eq!(TemperatureHint::Hot.to_emoji(), "🔥");
+    /// ```
+    pub fn to_emoji(&self) -> &'static str {
+        match self {
+            TemperatureHint::Freezing => "🥶",
+            TemperatureHint::Cold => "❄️",
+            TemperatureHint::Warm => "😐",
+            TemperatureHint::Hot => "🔥",
+            TemperatureHint::Burning => "💥",
+        }
+    }
+}
+
+impl fmt::Display for TemperatureHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            TemperatureHint::Freezing => "freezing cold",
+            TemperatureHint::Cold => "cold",
+            TemperatureHint::Warm => "warm",
+            TemperatureHint::Hot => "hot",
+            TemperatureHint::Burning => "burning hot",
+        };
+
+        f.pad(text)
+    }
+}
+
+impl Guess {
+    /// Describes how close the guess is to the target using the default thresholds:
+    /// `0` is `Burning`, up to `5` is `Hot`, up to `15` is `Warm`, up to `30` is `Cold`
+    /// and anything further is `Freezing`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use guessing_utils::temperature::TemperatureHint;
+    /// 
+    /// let secret = Guess::new(42).unwrap();
+    /// 
+    /// assert_eq!(Guess::new(90).unwrap().temperature_hint(&secret), TemperatureHint::Freezing);
+    /// ```
+    pub fn temperature_hint(&self, target: &Guess) -> TemperatureHint {
+        TemperatureHint::from_distance(self.abs_diff(target))
+    }
+
+    /// Describes how close the guess is to the target using custom thresholds.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// use guessing_utils::temperature::{TemperatureConfig, TemperatureHint};
+    /// 
+    /// let config = TemperatureConfig::new(1, 2, 3).unwrap();
+    /// let secret = Guess::new(42).unwrap();
+    /// 
+    /// assert_eq!(Guess::new(45).unwrap().temperature_hint_with_config(&secret, &config), TemperatureHint::Cold);
+    /// ```
+    pub fn temperature_hint_with_config(&self, target: &Guess, config: &TemperatureConfig) -> TemperatureHint {
+        TemperatureHint::from_distance_with_config(self.abs_diff(target), config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temperature_zones_test() {
+        let cases = [
+            (0, TemperatureHint::Burning),
+            (1, TemperatureHint::Hot),
+            (3, TemperatureHint::Hot),
+            (HOT_THRESHOLD, TemperatureHint::Hot),
+            (HOT_THRESHOLD + 1, TemperatureHint::Warm),
+            (10, TemperatureHint::Warm),
+            (WARM_THRESHOLD, TemperatureHint::Warm),
+            (WARM_THRESHOLD + 1, TemperatureHint::Cold),
+            (25, TemperatureHint::Cold),
+            (COLD_THRESHOLD, TemperatureHint::Cold),
+            (COLD_THRESHOLD + 1, TemperatureHint::Freezing),
+            (70, TemperatureHint::Freezing),
+            (100, TemperatureHint::Freezing),
+        ];
+
+        let target = Guess::MIN;
+        for (distance, expected) in cases {
+            let guess = Guess::new(distance as i32).unwrap();
+
+            assert_eq!(TemperatureHint::from_distance(distance), expected);
+            assert_eq!(guess.temperature_hint(&target), expected);
+            assert_eq!(target.temperature_hint(&guess), expected);
+        }
+    }
+
+    #[test]
+    fn temperature_display_test() {
+        assert_eq!(TemperatureHint::Burning.to_string(), "burning hot");
+        assert_eq!(TemperatureHint::Freezing.to_emoji(), "🥶");
+        assert_eq!(TemperatureHint::Burning.to_emoji(), "💥");
+    }
+
+    #[test]
+    fn temperature_config_test() {
+        assert_eq!(TemperatureConfig::new(5, 15, 30), Some(TemperatureConfig::default()));
+        assert_eq!(TemperatureConfig::default().hot(1).warm(2).cold(3), TemperatureConfig::new(1, 2, 3).unwrap());
+        assert_eq!(TemperatureConfig::new(0, 15, 30), None);
+        assert_eq!(TemperatureConfig::new(5, 5, 30), None);
+        assert_eq!(TemperatureConfig::new(5, 15, 10), None);
+        assert!(!TemperatureConfig::default().cold(10).is_valid());
+    }
+
+    #[test]
+    fn temperature_hint_with_config_test() {
+        let target = Guess::MIDPOINT;
+        let default = TemperatureConfig::default();
+        let tight = TemperatureConfig::new(1, 2, 3).unwrap();
+
+        let mut freezing = 0;
+        for val in 0..=100 {
+            let guess = Guess::new(val).unwrap();
+
+            assert_eq!(guess.temperature_hint_with_config(&target, &default), guess.temperature_hint(&target));
+
+            if guess.temperature_hint_with_config(&target, &tight) == TemperatureHint::Freezing {
+                freezing += 1;
+            }
+        }
+
+        assert_eq!(freezing, 101 - 7);
+    }
+}
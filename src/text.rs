@@ -0,0 +1,913 @@
+//! Textual representations of guesses.
+
+use crate::err::{GuessError, GuessRangeError};
+use crate::{Guess, GUESS_RANGE_MAX};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+const ONES: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// ITU Morse code for the decimal digits, indexed by digit.
+pub const MORSE_DIGITS: [&str; 10] = [
+    "-----", ".----", "..---", "...--", "....-", ".....", "-....", "--...", "---..", "----.",
+];
+
+const TALLY_ONE: char = '\u{1D377}';
+const TALLY_FIVE: char = '\u{1D378}';
+
+/// Keycap emoji for the decimal digits, each a digit followed by a variation selector and a combining keycap.
+const KEYCAP_DIGITS: [&str; 10] = [
+    "0\u{FE0F}\u{20E3}", "1\u{FE0F}\u{20E3}", "2\u{FE0F}\u{20E3}", "3\u{FE0F}\u{20E3}", "4\u{FE0F}\u{20E3}",
+    "5\u{FE0F}\u{20E3}", "6\u{FE0F}\u{20E3}", "7\u{FE0F}\u{20E3}", "8\u{FE0F}\u{20E3}", "9\u{FE0F}\u{20E3}",
+];
+
+/// The Braille sign marking the following cells as digits.
+const BRAILLE_NUMBER_INDICATOR: char = '⠼';
+
+/// The Braille digits, indexed by digit.
+const BRAILLE_DIGITS: [char; 10] = ['⠚', '⠁', '⠃', '⠉', '⠙', '⠑', '⠋', '⠛', '⠓', '⠊'];
+
+/// The standard base64 alphabet, indexed by the value of a 6-bit group.
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const ROMAN_NUMERALS: [(i32, &str); 9] = [
+    (100, "C"), (90, "XC"), (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+];
+
+impl Guess {
+    /// Parses every `separator` separated value in a string slice, trimming whitespace around each one.
+    /// Each value gets its own result, so callers can decide how to handle partial failures.
+    /// An empty input produces an empty list, while empty values between separators produce errors.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// let list = Guess::parse_list("1, 2, 200", ',');
+    /// 
+    /// assert_eq!(list[0], Ok(Guess::new(1).unwrap()));
+    /// assert_eq!(list[1], Ok(Guess::new(2).unwrap()));
+    /// assert!(list[2].is_err());
+    /// ```
+    pub fn parse_list(input: &str, separator: char) -> Vec<Result<Guess, GuessError>> {
+        if input.trim().is_empty() {
+            return Vec::new();
+        }
+
+        input.split(separator).map(|token| Guess::parse(token.trim())).collect()
+    }
+
+    /// Parses every `separator` separated value in a string slice, stopping at the first error.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::parse_list_strict("4; 2", ';').unwrap().len(), 2);
+    /// assert!(Guess::parse_list_strict("4; x", ';').is_err());
+    /// ```
+    pub fn parse_list_strict(input: &str, separator: char) -> Result<Vec<Guess>, GuessError> {
+        Guess::parse_list(input, separator).into_iter().collect()
+    }
+
+    /// Formats the guess as a percentage, like `"57%"`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(57).unwrap().as_percentage_string(), "57%");
+    /// ```
+    pub fn as_percentage_string(&self) -> String {
+        format!("{}%", self.val)
+    }
+
+    /// Parses a percentage string slice, the inverse of `as_percentage_string()`.
+    /// The trailing `%` is optional and surrounding whitespace is ignored.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_percentage_string("57%"), Ok(Guess::new(57).unwrap()));
+    /// assert_eq!(Guess::from_percentage_string("57"), Ok(Guess::new(57).unwrap()));
+    /// assert!(Guess::from_percentage_string("150%").is_err());
+    /// ```
+    pub fn from_percentage_string(s: &str) -> Result<Guess, GuessError> {
+        let s = s.trim();
+
+        Guess::parse(s.strip_suffix('%').unwrap_or(s).trim())
+    }
+
+    /// Parses a string slice in the given radix and creates a new object from it.
+    /// Surrounding whitespace is ignored.
+    /// # Panics
+    /// Panics if `radix` is not in the range from 2 to 36.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::parse_radix("52", 8), Ok(Guess::new(42).unwrap()));
+    /// assert!(Guess::parse_radix("z", 16).is_err());
+    /// ```
+    pub fn parse_radix(val: &str, radix: u32) -> Result<Guess, GuessError> {
+        let val = i32::from_str_radix(val.trim(), radix)?;
+
+        Ok(Guess::new(val)?)
+    }
+
+    /// Parses a hexadecimal string slice, with or without a `0x` prefix.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::parse_hex("0x2A"), Ok(Guess::new(42).unwrap()));
+    /// assert_eq!(Guess::parse_hex("2a"), Ok(Guess::new(42).unwrap()));
+    /// ```
+    pub fn parse_hex(val: &str) -> Result<Guess, GuessError> {
+        let val = val.trim();
+        let digits = val.strip_prefix("0x").or_else(|| val.strip_prefix("0X")).unwrap_or(val);
+
+        Guess::parse_radix(digits, 16)
+    }
+
+    /// Parses a binary string slice, with or without a `0b` prefix.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::parse_binary("0b101010"), Ok(Guess::new(42).unwrap()));
+    /// assert_eq!(Guess::parse_binary("101010"), Ok(Guess::new(42).unwrap()));
+    /// ```
+    pub fn parse_binary(val: &str) -> Result<Guess, GuessError> {
+        let val = val.trim();
+        let digits = val.strip_prefix("0b").or_else(|| val.strip_prefix("0B")).unwrap_or(val);
+
+        Guess::parse_radix(digits, 2)
+    }
+
+    /// Formats the guess as uppercase hexadecimal without a prefix, the inverse of `parse_hex()`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().to_hex_string(), "2A");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        format!("{:X}", self.val)
+    }
+
+    /// Converts the guess to English words, like `"fifty-seven"` or `"one hundred"`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(13).unwrap().to_words(), "thirteen");
+    /// assert_eq!(Guess::new(57).unwrap().to_words(), "fifty-seven");
+    /// assert_eq!(Guess::MAX.to_words(), "one hundred");
+    /// ```
+    pub fn to_words(&self) -> String {
+        let val = self.val as usize;
+
+        match val {
+            0..=19 => ONES[val].to_string(),
+            100 => "one hundred".to_string(),
+            _ if val.is_multiple_of(10) => TENS[val / 10].to_string(),
+            _ => format!("{}-{}", TENS[val / 10], ONES[val % 10]),
+        }
+    }
+
+    /// Creates a new object from English words, the inverse of `to_words()`.
+    /// The input is case-insensitive, and the tens may be joined with either a hyphen or a space.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_words("Twenty-One"), Ok(Guess::new(21).unwrap()));
+    /// assert_eq!(Guess::from_words("twenty one"), Ok(Guess::new(21).unwrap()));
+    /// assert!(Guess::from_words("eleventy").is_err());
+    /// ```
+    pub fn from_words(s: &str) -> Result<Guess, GuessError> {
+        let invalid = || GuessError::InvalidFormat(s.to_string());
+
+        let lower = s.to_lowercase().replace('-', " ");
+        let words: Vec<&str> = lower.split_whitespace().collect();
+
+        let position = |table: &[&str], word: &str| table.iter().position(|&w| !w.is_empty() && w == word);
+
+        let val = match words.as_slice() {
+            ["one", "hundred"] => 100,
+            [word] => match position(&ONES, word) {
+                Some(ones) => ones,
+                None => position(&TENS, word).ok_or_else(invalid)? * 10,
+            },
+            [tens, ones] => {
+                let tens = position(&TENS, tens).ok_or_else(invalid)?;
+                let ones = position(&ONES[1..10], ones).ok_or_else(invalid)? + 1;
+                tens * 10 + ones
+            }
+            _ => return Err(invalid()),
+        };
+
+        Ok(Guess { val: val as i32 })
+    }
+
+    /// Formats the guess left-padded with `pad_char` to at least `width` characters.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(7).unwrap().to_padded_string(3, '0'), "007");
+    /// assert_eq!(Guess::new(42).unwrap().to_padded_string(5, ' '), "   42");
+    /// ```
+    pub fn to_padded_string(&self, width: usize, pad_char: char) -> String {
+        let digits = self.val.to_string();
+        let padding = width.saturating_sub(digits.len());
+
+        core::iter::repeat_n(pad_char, padding).chain(digits.chars()).collect()
+    }
+
+    /// Formats the guess left-padded with zeros to at least `width` characters.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(7).unwrap().to_zero_padded(3), "007");
+    /// ```
+    pub fn to_zero_padded(&self, width: usize) -> String {
+        self.to_padded_string(width, '0')
+    }
+
+    /// Converts the guess to unary notation, with pipes grouped in fours and separated by spaces.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(7).unwrap().to_unary(), "|||| |||");
+    /// assert_eq!(Guess::MIN.to_unary(), "");
+    /// ```
+    pub fn to_unary(&self) -> String {
+        (0..self.val)
+            .map(|i| if i > 0 && i % 4 == 0 { " |" } else { "|" })
+            .collect()
+    }
+
+    /// Converts the guess to Unicode tally marks, using a five-bar mark for each group of five.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(7).unwrap().to_tally_marks(), "\u{1D378}\u{1D377}\u{1D377}");
+    /// ```
+    pub fn to_tally_marks(&self) -> String {
+        let fives = (self.val / 5) as usize;
+        let ones = (self.val % 5) as usize;
+
+        core::iter::repeat_n(TALLY_FIVE, fives)
+            .chain(core::iter::repeat_n(TALLY_ONE, ones))
+            .collect()
+    }
+
+    /// Creates a new object by counting the pipes in unary notation, the inverse of `to_unary()`.
+    /// Whitespace is ignored, any other character is rejected.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_unary(" |||| ||| "), Ok(Guess::new(7).unwrap()));
+    /// assert!(Guess::from_unary("||x").is_err());
+    /// ```
+    pub fn from_unary(s: &str) -> Result<Guess, GuessError> {
+        let mut count = 0;
+
+        for c in s.chars().filter(|c| !c.is_whitespace()) {
+            if c != '|' {
+                return Err(GuessError::InvalidFormat(s.to_string()));
+            }
+            count += 1;
+        }
+
+        if count > GUESS_RANGE_MAX {
+            return Err(GuessRangeError(count).into());
+        }
+
+        Ok(Guess { val: count })
+    }
+
+    /// Encodes each decimal digit of the guess in Morse code, separated by single spaces.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().encode_morse(), "....- ..---");
+    /// ```
+    pub fn encode_morse(&self) -> String {
+        self.val
+            .to_string()
+            .bytes()
+            .map(|digit| MORSE_DIGITS[(digit - b'0') as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Creates a new object from Morse-encoded digits, the inverse of `encode_morse()`.
+    /// Digits may be separated by any amount of whitespace.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::decode_morse("  ....-   ..--- "), Ok(Guess::new(42).unwrap()));
+    /// assert!(Guess::decode_morse("...-.").is_err());
+    /// ```
+    pub fn decode_morse(s: &str) -> Result<Guess, GuessError> {
+        let digits = s
+            .split_whitespace()
+            .map(|code| {
+                MORSE_DIGITS
+                    .iter()
+                    .position(|&digit| digit == code)
+                    .map(|digit| char::from(b'0' + digit as u8))
+            })
+            .collect::<Option<String>>()
+            .filter(|digits| !digits.is_empty())
+            .ok_or_else(|| GuessError::InvalidFormat(s.to_string()))?;
+
+        Guess::parse(&digits)
+    }
+
+    /// Converts each decimal digit of the guess to a keycap emoji, like `"4️⃣2️⃣"`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().to_digit_emoji(), "4\u{FE0F}\u{20E3}2\u{FE0F}\u{20E3}");
+    /// assert_eq!(Guess::MAX.to_digit_emoji(), "1️⃣0️⃣0️⃣");
+    /// ```
+    pub fn to_digit_emoji(&self) -> String {
+        self.val
+            .to_string()
+            .bytes()
+            .map(|digit| KEYCAP_DIGITS[(digit - b'0') as usize])
+            .collect()
+    }
+
+    /// Creates a new object from keycap emoji digits, the inverse of `to_digit_emoji()`.
+    /// Every digit must be a complete keycap sequence, so plain digits are rejected.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_digit_emoji("4️⃣2️⃣"), Ok(Guess::new(42).unwrap()));
+    /// assert!(Guess::from_digit_emoji("4️⃣2").is_err());
+    /// ```
+    pub fn from_digit_emoji(s: &str) -> Result<Guess, GuessError> {
+        let invalid = || GuessError::InvalidFormat(s.to_string());
+
+        let mut digits = String::new();
+        let mut rest = s;
+        while !rest.is_empty() {
+            let (digit, stripped) = KEYCAP_DIGITS
+                .iter()
+                .enumerate()
+                .find_map(|(digit, keycap)| rest.strip_prefix(keycap).map(|stripped| (digit, stripped)))
+                .ok_or_else(invalid)?;
+
+            digits.push(char::from(b'0' + digit as u8));
+            rest = stripped;
+        }
+
+        if digits.is_empty() {
+            return Err(invalid());
+        }
+
+        Guess::parse(&digits)
+    }
+
+    /// Encodes the guess as a single byte in standard padded base64, like `"QQ=="` for `65`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(65).unwrap().encode_base64(), "QQ==");
+    /// ```
+    pub fn encode_base64(&self) -> String {
+        let byte = self.val as u8;
+        let high = BASE64_ALPHABET[(byte >> 2) as usize];
+        let low = BASE64_ALPHABET[((byte & 0b11) << 4) as usize];
+
+        format!("{}{}==", high as char, low as char)
+    }
+
+    /// Decodes a guess from standard padded base64, the inverse of `encode_base64()`.
+    /// Inputs which do not encode exactly one byte produce an `InvalidFormat` error,
+    /// while a byte above 100 produces a `RangeError`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::decode_base64("QQ=="), Ok(Guess::new(65).unwrap()));
+    /// assert!(Guess::decode_base64("QUI=").is_err());
+    /// ```
+    pub fn decode_base64(s: &str) -> Result<Guess, GuessError> {
+        let invalid = || GuessError::InvalidFormat(s.to_string());
+        let sextet = |c: u8| BASE64_ALPHABET.iter().position(|&b| b == c).map(|pos| pos as u8);
+
+        let (high, low) = match s.as_bytes() {
+            [high, low, b'=', b'='] => (sextet(*high).ok_or_else(invalid)?, sextet(*low).ok_or_else(invalid)?),
+            _ => return Err(invalid()),
+        };
+
+        // The unused low bits must be zero, so every byte has a single encoding.
+        if low & 0b1111 != 0 {
+            return Err(invalid());
+        }
+
+        let byte = (high << 2) | (low >> 4);
+        Ok(Guess::from_bytes([byte])?)
+    }
+
+    /// Converts the guess to Unicode Braille: the number indicator `⠼` followed by the Braille digits.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().to_braille(), "⠼⠙⠃");
+    /// ```
+    pub fn to_braille(&self) -> String {
+        let digits = self.val.to_string();
+
+        core::iter::once(BRAILLE_NUMBER_INDICATOR)
+            .chain(digits.bytes().map(|digit| BRAILLE_DIGITS[(digit - b'0') as usize]))
+            .collect()
+    }
+
+    /// Creates a new object from Unicode Braille, the inverse of `to_braille()`.
+    /// The number indicator must precede the first digit only.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_braille("⠼⠙⠃"), Ok(Guess::new(42).unwrap()));
+    /// assert!(Guess::from_braille("⠙⠃").is_err());
+    /// ```
+    pub fn from_braille(s: &str) -> Result<Guess, GuessError> {
+        let invalid = || GuessError::InvalidFormat(s.to_string());
+
+        let digits = s
+            .strip_prefix(BRAILLE_NUMBER_INDICATOR)
+            .ok_or_else(invalid)?
+            .chars()
+            .map(|c| {
+                BRAILLE_DIGITS
+                    .iter()
+                    .position(|&digit| digit == c)
+                    .map(|digit| char::from(b'0' + digit as u8))
+            })
+            .collect::<Option<String>>()
+            .filter(|digits| !digits.is_empty())
+            .ok_or_else(invalid)?;
+
+        Guess::parse(&digits)
+    }
+
+    /// Converts the guess to a Roman numeral using subtractive notation, like `"XLII"`.
+    /// Returns `None` for zero, which has no Roman representation.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(42).unwrap().to_roman_numeral(), Some("XLII".to_string()));
+    /// assert_eq!(Guess::MIN.to_roman_numeral(), None);
+    /// ```
+    pub fn to_roman_numeral(&self) -> Option<String> {
+        if self.val == 0 {
+            return None;
+        }
+
+        let mut remaining = self.val;
+        let mut numeral = String::new();
+
+        for (value, symbol) in ROMAN_NUMERALS {
+            while remaining >= value {
+                numeral.push_str(symbol);
+                remaining -= value;
+            }
+        }
+
+        Some(numeral)
+    }
+
+    /// Converts the guess to an English ordinal, like `"1st"`, `"12th"` or `"42nd"`.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(21).unwrap().to_ordinal_string(), "21st");
+    /// assert_eq!(Guess::new(12).unwrap().to_ordinal_string(), "12th");
+    /// ```
+    pub fn to_ordinal_string(&self) -> String {
+        format!("{}{}", self.val, self.ordinal_suffix())
+    }
+
+    /// Gets the English ordinal suffix of the guess: `"st"`, `"nd"`, `"rd"` or `"th"`.
+    /// The teens always take `"th"`, whatever their last digit.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::new(3).unwrap().ordinal_suffix(), "rd");
+    /// assert_eq!(Guess::new(13).unwrap().ordinal_suffix(), "th");
+    /// ```
+    pub fn ordinal_suffix(&self) -> &'static str {
+        ordinal_suffix(self.val as u32)
+    }
+
+    /// Creates a new object from a Roman numeral, the inverse of `to_roman_numeral()`.
+    /// The input is case-insensitive, but must be in standard form.
+    /// # Example
+    /// ```
+    /// use guessing_utils::Guess;
+    /// 
+    /// assert_eq!(Guess::from_roman_numeral("xlii"), Ok(Guess::new(42).unwrap()));
+    /// assert!(Guess::from_roman_numeral("IIII").is_err());
+    /// ```
+    pub fn from_roman_numeral(s: &str) -> Result<Guess, GuessError> {
+        let invalid = || GuessError::InvalidFormat(s.to_string());
+
+        let upper = s.trim().to_uppercase();
+        let mut rest = upper.as_str();
+        let mut val = 0;
+
+        for (value, symbol) in ROMAN_NUMERALS {
+            while let Some(stripped) = rest.strip_prefix(symbol) {
+                val += value;
+                rest = stripped;
+            }
+        }
+
+        if !rest.is_empty() || val == 0 || val > GUESS_RANGE_MAX {
+            return Err(invalid());
+        }
+
+        // Greedy parsing accepts non-standard forms like "IIII", so only the canonical spelling is allowed.
+        let guess = Guess { val };
+        if guess.to_roman_numeral().as_deref() != Some(upper.as_str()) {
+            return Err(invalid());
+        }
+
+        Ok(guess)
+    }
+}
+
+/// Gets the English ordinal suffix of any number, so `111` is `"th"` and `121` is `"st"`.
+fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_guesses() -> impl Iterator<Item = Guess> {
+        (0..=100).map(|val| Guess::new(val).unwrap())
+    }
+
+    #[test]
+    fn to_words_test() {
+        let words = |val| Guess::new(val).unwrap().to_words();
+
+        assert_eq!(words(0), "zero");
+        assert_eq!(words(11), "eleven");
+        assert_eq!(words(13), "thirteen");
+        assert_eq!(words(19), "nineteen");
+        assert_eq!(words(20), "twenty");
+        assert_eq!(words(57), "fifty-seven");
+        assert_eq!(words(99), "ninety-nine");
+        assert_eq!(words(100), "one hundred");
+    }
+
+    #[test]
+    fn words_round_trip_test() {
+        for guess in all_guesses() {
+            assert_eq!(Guess::from_words(&guess.to_words()), Ok(guess));
+            assert_eq!(Guess::from_words(&guess.to_words().to_uppercase()), Ok(guess));
+            assert_eq!(Guess::from_words(&guess.to_words().replace('-', " ")), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn from_words_invalid_test() {
+        for input in ["", "twenty zero", "ten one", "one hundred one", "forty two three", "42", "two hundred"] {
+            assert_eq!(Guess::from_words(input), Err(GuessError::InvalidFormat(input.to_string())));
+        }
+
+        assert_eq!(Guess::from_words("  Forty   Two "), Ok(Guess::new(42).unwrap()));
+    }
+
+    #[test]
+    fn to_roman_numeral_test() {
+        let roman = |val| Guess::new(val).unwrap().to_roman_numeral();
+
+        assert_eq!(roman(0), None);
+        assert_eq!(roman(4).as_deref(), Some("IV"));
+        assert_eq!(roman(9).as_deref(), Some("IX"));
+        assert_eq!(roman(40).as_deref(), Some("XL"));
+        assert_eq!(roman(42).as_deref(), Some("XLII"));
+        assert_eq!(roman(90).as_deref(), Some("XC"));
+        assert_eq!(roman(99).as_deref(), Some("XCIX"));
+        assert_eq!(roman(100).as_deref(), Some("C"));
+    }
+
+    #[test]
+    fn roman_numeral_round_trip_test() {
+        for guess in all_guesses().skip(1) {
+            let numeral = guess.to_roman_numeral().unwrap();
+
+            assert_eq!(Guess::from_roman_numeral(&numeral), Ok(guess));
+            assert_eq!(Guess::from_roman_numeral(&numeral.to_lowercase()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn from_roman_numeral_invalid_test() {
+        for input in ["", "IIII", "VX", "IC", "CI", "CC", "ABC", "XLIIX"] {
+            assert_eq!(Guess::from_roman_numeral(input), Err(GuessError::InvalidFormat(input.to_string())));
+        }
+    }
+
+    #[test]
+    fn to_padded_string_test() {
+        for guess in all_guesses() {
+            let digits = guess.get().to_string();
+
+            assert_eq!(guess.to_padded_string(0, '0'), digits);
+            assert_eq!(guess.to_padded_string(1, '0'), digits);
+            assert_eq!(guess.to_padded_string(2, ' ').len(), digits.len().max(2));
+        }
+
+        let seven = Guess::new(7).unwrap();
+        assert_eq!(seven.to_padded_string(2, ' '), " 7");
+        assert_eq!(seven.to_padded_string(3, ' '), "  7");
+        assert_eq!(seven.to_padded_string(3, '0'), "007");
+        assert_eq!(Guess::MAX.to_padded_string(2, '0'), "100");
+    }
+
+    #[test]
+    fn to_zero_padded_test() {
+        for guess in all_guesses().take(10) {
+            assert_eq!(guess.to_zero_padded(3), format!("00{}", guess.get()));
+            assert_eq!(guess.to_zero_padded(3), guess.to_padded_string(3, '0'));
+        }
+    }
+
+    #[test]
+    fn to_unary_test() {
+        let unary = |val| Guess::new(val).unwrap().to_unary();
+
+        assert_eq!(unary(0), "");
+        assert_eq!(unary(1), "|");
+        assert_eq!(unary(4), "||||");
+        assert_eq!(unary(7), "|||| |||");
+        assert_eq!(unary(8), "|||| ||||");
+        assert_eq!(unary(9), "|||| |||| |");
+    }
+
+    #[test]
+    fn unary_round_trip_test() {
+        for guess in all_guesses() {
+            assert_eq!(Guess::from_unary(&guess.to_unary()), Ok(guess));
+            assert_eq!(Guess::from_unary(&format!("  {}  ", guess.to_unary())), Ok(guess));
+        }
+
+        assert_eq!(Guess::from_unary("   "), Ok(Guess::MIN));
+        assert_eq!(Guess::from_unary(&"|".repeat(101)), Err(GuessError::RangeError(GuessRangeError(101))));
+        assert_eq!(Guess::from_unary("|-|"), Err(GuessError::InvalidFormat("|-|".to_string())));
+    }
+
+    #[test]
+    fn to_tally_marks_test() {
+        assert_eq!(Guess::MIN.to_tally_marks(), "");
+        assert_eq!(Guess::new(5).unwrap().to_tally_marks(), "\u{1D378}");
+
+        for guess in all_guesses() {
+            let marks = guess.to_tally_marks();
+            let fives = marks.chars().filter(|&c| c == TALLY_FIVE).count() as i32;
+            let ones = marks.chars().filter(|&c| c == TALLY_ONE).count() as i32;
+
+            assert_eq!(fives * 5 + ones, guess.get());
+            assert!(ones < 5);
+        }
+    }
+
+    #[test]
+    fn encode_morse_test() {
+        assert_eq!(Guess::MIN.encode_morse(), "-----");
+        assert_eq!(Guess::new(42).unwrap().encode_morse(), "....- ..---");
+        assert_eq!(Guess::MAX.encode_morse(), ".---- ----- -----");
+    }
+
+    #[test]
+    fn morse_round_trip_test() {
+        for guess in all_guesses() {
+            assert_eq!(Guess::decode_morse(&guess.encode_morse()), Ok(guess));
+            assert_eq!(Guess::decode_morse(&guess.encode_morse().replace(' ', "\t  ")), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn decode_morse_invalid_test() {
+        for input in ["", "   ", ".-.-.", "....-..---", "42"] {
+            assert_eq!(Guess::decode_morse(input), Err(GuessError::InvalidFormat(input.to_string())));
+        }
+
+        assert!(matches!(Guess::decode_morse("..--- ----- -----"), Err(GuessError::RangeError(_))));
+    }
+
+    #[test]
+    fn parse_radix_test() {
+        let answer = Ok(Guess::new(42).unwrap());
+
+        assert_eq!(Guess::parse_radix("2A", 16), answer);
+        assert_eq!(Guess::parse_radix("101010", 2), answer);
+        assert_eq!(Guess::parse_radix("52", 8), answer);
+        assert_eq!(Guess::parse_radix(" 1G ", 26), answer);
+        assert_eq!(Guess::parse_radix("2S", 36), Ok(Guess::MAX));
+        assert_eq!(Guess::parse_hex("0X2a"), answer);
+        assert_eq!(Guess::parse_binary("0B101010"), answer);
+    }
+
+    #[test]
+    fn parse_radix_invalid_test() {
+        assert!(matches!(Guess::parse_radix("102", 2), Err(GuessError::ParseError(_))));
+        assert!(matches!(Guess::parse_radix("", 10), Err(GuessError::ParseError(_))));
+        assert!(matches!(Guess::parse_hex("0x"), Err(GuessError::ParseError(_))));
+        assert!(matches!(Guess::parse_hex("0xg"), Err(GuessError::ParseError(_))));
+        assert!(matches!(Guess::parse_binary("0b2"), Err(GuessError::ParseError(_))));
+        assert_eq!(Guess::parse_hex("FF"), Err(GuessError::RangeError(GuessRangeError(255))));
+        assert_eq!(Guess::parse_binary("-1"), Err(GuessError::RangeError(GuessRangeError(-1))));
+    }
+
+    #[test]
+    fn radix_round_trip_test() {
+        for guess in all_guesses() {
+            assert_eq!(Guess::parse_hex(&guess.to_hex_string()), Ok(guess));
+            assert_eq!(Guess::parse_hex(&format!("{:#x}", guess)), Ok(guess));
+            assert_eq!(Guess::parse_binary(&format!("{:b}", guess)), Ok(guess));
+            assert_eq!(Guess::parse_binary(&format!("{:#b}", guess)), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn parse_list_test() {
+        let list = Guess::parse_list("1,2,200,abc", ',');
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list[0], Ok(Guess::new(1).unwrap()));
+        assert_eq!(list[1], Ok(Guess::new(2).unwrap()));
+        assert_eq!(list[2], Err(GuessError::RangeError(GuessRangeError(200))));
+        assert!(matches!(list[3], Err(GuessError::ParseError(_))));
+
+        assert!(Guess::parse_list("", ',').is_empty());
+        assert!(Guess::parse_list("  ", ',').is_empty());
+        assert_eq!(Guess::parse_list(" 42 ", ','), [Ok(Guess::new(42).unwrap())]);
+        assert_eq!(Guess::parse_list("4,2", ';').len(), 1);
+    }
+
+    #[test]
+    fn parse_list_empty_tokens_test() {
+        let list = Guess::parse_list(",1,2,", ',');
+
+        assert_eq!(list.len(), 4);
+        assert!(list[0].is_err());
+        assert_eq!(list[1], Ok(Guess::new(1).unwrap()));
+        assert_eq!(list[2], Ok(Guess::new(2).unwrap()));
+        assert!(list[3].is_err());
+    }
+
+    #[test]
+    fn parse_list_strict_test() {
+        let guesses = [Guess::new(1).unwrap(), Guess::new(50).unwrap(), Guess::MAX];
+
+        assert_eq!(Guess::parse_list_strict("1 | 50 | 100", '|'), Ok(guesses.to_vec()));
+        assert_eq!(Guess::parse_list_strict("", '|'), Ok(Vec::new()));
+        assert_eq!(
+            Guess::parse_list_strict("1,2,200,abc", ','),
+            Err(GuessError::RangeError(GuessRangeError(200)))
+        );
+    }
+
+    #[test]
+    fn percentage_string_round_trip_test() {
+        for guess in all_guesses() {
+            assert_eq!(Guess::from_percentage_string(&guess.as_percentage_string()), Ok(guess));
+            assert_eq!(Guess::from_percentage_string(&guess.get().to_string()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn from_percentage_string_test() {
+        assert_eq!(Guess::from_percentage_string("57%"), Ok(Guess::new(57).unwrap()));
+        assert_eq!(Guess::from_percentage_string("0%"), Ok(Guess::MIN));
+        assert_eq!(Guess::from_percentage_string(" 100 % "), Ok(Guess::MAX));
+        assert_eq!(Guess::from_percentage_string("150%"), Err(GuessError::RangeError(GuessRangeError(150))));
+        assert!(matches!(Guess::from_percentage_string("abc%"), Err(GuessError::ParseError(_))));
+        assert!(matches!(Guess::from_percentage_string("57%%"), Err(GuessError::ParseError(_))));
+        assert!(matches!(Guess::from_percentage_string(""), Err(GuessError::ParseError(_))));
+    }
+
+    #[test]
+    fn digit_emoji_test() {
+        assert_eq!(Guess::new(42).unwrap().to_digit_emoji(), "4️⃣2️⃣");
+        assert_eq!(Guess::MAX.to_digit_emoji(), "1️⃣0️⃣0️⃣");
+        assert_eq!(Guess::MIN.to_digit_emoji(), "0️⃣");
+
+        for guess in all_guesses() {
+            assert_eq!(Guess::from_digit_emoji(&guess.to_digit_emoji()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn from_digit_emoji_invalid_test() {
+        for input in ["", "42", "4️⃣2", "4 2️⃣", "4️⃣ 2️⃣", "4\u{20E3}", "🔥", "4️⃣2️⃣!"] {
+            assert_eq!(Guess::from_digit_emoji(input), Err(GuessError::InvalidFormat(input.to_string())), "{}", input);
+        }
+
+        assert_eq!(Guess::from_digit_emoji("1️⃣0️⃣1️⃣"), Err(GuessError::RangeError(GuessRangeError(101))));
+    }
+
+    #[test]
+    fn base64_test() {
+        assert_eq!(Guess::new(65).unwrap().encode_base64(), "QQ==");
+        assert_eq!(Guess::MIN.encode_base64(), "AA==");
+        assert_eq!(Guess::MAX.encode_base64(), "ZA==");
+        assert_eq!(Guess::new(63).unwrap().encode_base64(), "Pw==");
+
+        for guess in all_guesses() {
+            assert_eq!(Guess::decode_base64(&guess.encode_base64()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn decode_base64_invalid_test() {
+        for input in ["", "QQ", "QQ=", "QQ===", "Q===", "QUI=", "QUJD", "Q!==", "!Q==", "QR==", " QQ=="] {
+            assert_eq!(Guess::decode_base64(input), Err(GuessError::InvalidFormat(input.to_string())), "{}", input);
+        }
+
+        assert_eq!(Guess::decode_base64("ZQ=="), Err(GuessError::RangeError(GuessRangeError(101))));
+        assert_eq!(Guess::decode_base64("/w=="), Err(GuessError::RangeError(GuessRangeError(255))));
+    }
+
+    #[test]
+    fn braille_test() {
+        assert_eq!(Guess::new(42).unwrap().to_braille(), "\u{283C}\u{2819}\u{2803}");
+        assert_eq!(Guess::MIN.to_braille(), "⠼⠚");
+        assert_eq!(Guess::MAX.to_braille(), "⠼⠁⠚⠚");
+
+        for guess in all_guesses() {
+            assert_eq!(Guess::from_braille(&guess.to_braille()), Ok(guess));
+        }
+    }
+
+    #[test]
+    fn from_braille_invalid_test() {
+        for input in ["", "⠼", "⠙⠃", "⠼⠙⠼⠃", "⠼42", "⠼⠙ ⠃", " ⠼⠙⠃", "⠼⠙⠃⠀"] {
+            assert_eq!(Guess::from_braille(input), Err(GuessError::InvalidFormat(input.to_string())), "{}", input);
+        }
+
+        assert_eq!(Guess::from_braille("⠼⠁⠚⠁"), Err(GuessError::RangeError(GuessRangeError(101))));
+    }
+
+    #[test]
+    fn ordinal_test() {
+        let suffixes = [
+            "th", "st", "nd", "rd", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th", "th",
+            "th", "th", "th",
+        ];
+        for (val, suffix) in suffixes.into_iter().enumerate() {
+            let guess = Guess::new(val as i32).unwrap();
+
+            assert_eq!(guess.ordinal_suffix(), suffix);
+            assert_eq!(guess.to_ordinal_string(), format!("{}{}", val, suffix));
+        }
+
+        let cases = [(21, "21st"), (22, "22nd"), (23, "23rd"), (42, "42nd"), (73, "73rd"), (99, "99th"), (100, "100th")];
+        for (val, ordinal) in cases {
+            assert_eq!(Guess::new(val).unwrap().to_ordinal_string(), ordinal);
+        }
+
+        for (n, suffix) in [(111, "th"), (112, "th"), (113, "th"), (121, "st"), (122, "nd"), (1001, "st")] {
+            assert_eq!(ordinal_suffix(n), suffix);
+        }
+    }
+}